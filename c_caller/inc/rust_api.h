@@ -1,5 +1,12 @@
+#include <stdbool.h>
 #include <stdint.h>
 
+// Functions returning a pointer return NULL when the operation fails, for example
+// on mismatching matrix sizes or a ring degree of 0.
+
+// --- Strings ---
+void string_free(char *string);
+
 // --- Complex numbers ---
 typedef struct complex
 {
@@ -21,12 +28,31 @@ typedef struct polynomial
 } polynomial_t;
 
 polynomial_t *gen_random_polynomial();
+polynomial_t *gen_random_polynomial_seeded(uint64_t seed);
+polynomial_t *polynomial_from_coefficients(const complex_t *coefficients, size_t len);
+polynomial_t *polynomial_parse(const char *text);
+polynomial_t *polynomial_clone(const polynomial_t *polynomial);
 void polynomial_free(polynomial_t *polynomial);
 
+size_t polynomial_degree(const polynomial_t *polynomial);
+const complex_t *polynomial_coefficients(const polynomial_t *polynomial);
+char *polynomial_to_string(const polynomial_t *polynomial);
+
 polynomial_t *polynomial_add(polynomial_t *a, polynomial_t *b);
 polynomial_t *polynomial_mul(polynomial_t *a, polynomial_t *b);
 polynomial_t *polynomial_add_in_ring(polynomial_t *a, polynomial_t *b, size_t ring_degree);
 polynomial_t *polynomial_mul_in_ring(polynomial_t *a, polynomial_t *b, size_t ring_degree);
+polynomial_t *polynomial_neg(polynomial_t *polynomial);
+polynomial_t *polynomial_reduce_to(polynomial_t *polynomial, size_t ring_degree);
+void polynomial_euclidean_division(polynomial_t *a, polynomial_t *b, polynomial_t **quotient, polynomial_t **remainder);
+
+// --- Seeded random generation ---
+typedef struct rng rng_t;
+
+rng_t *rng_new(uint64_t seed);
+void rng_free(rng_t *rng);
+
+polynomial_t *gen_random_polynomial_from_rng(rng_t *rng);
 
 // --- Matrices ---
 typedef struct matrix
@@ -37,9 +63,41 @@ typedef struct matrix
 } matrix_t;
 
 matrix_t *matrix_new(polynomial_t *polynomial, size_t width, size_t height);
+matrix_t *matrix_from_polynomials(const polynomial_t *const *content, size_t width, size_t height);
+matrix_t *matrix_clone(const matrix_t *matrix);
 void matrix_free(matrix_t *matrix);
 
+size_t matrix_width(const matrix_t *matrix);
+size_t matrix_height(const matrix_t *matrix);
+// Returns NULL if (`row`, `column`) is out of bounds.
+polynomial_t *matrix_get(const matrix_t *matrix, size_t row, size_t column);
+char *matrix_to_string(const matrix_t *matrix);
+
 matrix_t *matrix_add(matrix_t *a, matrix_t *b);
 matrix_t *matrix_mul(matrix_t *a, matrix_t *b);
 matrix_t *matrix_add_in_ring(matrix_t *a, matrix_t *b, size_t ring_degree);
 matrix_t *matrix_mul_in_ring(matrix_t *a, matrix_t *b, size_t ring_degree);
+
+// --- Complex matrices and numeric linear algebra ---
+// Unlike the functions above, these borrow their matrix arguments.
+typedef struct complex_matrix complex_matrix_t;
+
+complex_matrix_t *complex_matrix_from_values(const complex_t *values, size_t width, size_t height);
+void complex_matrix_free(complex_matrix_t *matrix);
+
+size_t complex_matrix_width(const complex_matrix_t *matrix);
+size_t complex_matrix_height(const complex_matrix_t *matrix);
+// Returns false if (`row`, `column`) is out of bounds.
+bool complex_matrix_get(const complex_matrix_t *matrix, size_t row, size_t column, complex_t *value);
+
+complex_matrix_t *complex_matrix_mul(const complex_matrix_t *a, const complex_matrix_t *b);
+complex_matrix_t *complex_matrix_solve(const complex_matrix_t *a, const complex_matrix_t *b);
+// `PA = LU`, with `permutation` the rows of `A` in `PA`, which must have room for `width`
+// values. Returns false if the matrix isn't square.
+bool complex_matrix_lu(const complex_matrix_t *matrix, complex_matrix_t **l, complex_matrix_t **u, size_t *permutation);
+void complex_matrix_qr(const complex_matrix_t *matrix, complex_matrix_t **q, complex_matrix_t **r);
+bool complex_matrix_determinant(const complex_matrix_t *matrix, complex_t *determinant);
+size_t complex_matrix_rank(const complex_matrix_t *matrix);
+float complex_matrix_condition_number(const complex_matrix_t *matrix);
+// `eigenvalues` must have room for `width` values.
+bool complex_matrix_eigenvalues(const complex_matrix_t *matrix, complex_t *eigenvalues);
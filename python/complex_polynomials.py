@@ -0,0 +1,442 @@
+"""Python bindings for the complex polynomials library.
+
+This is a thin `ctypes` shim over the C API exported by the Rust library (see
+`rust/src/ffi`). The shared library is looked up in the following order:
+
+- the path stored in the `COMPLEX_POLYNOMIALS_LIB` environment variable,
+- `rust/target/release`, then `rust/target/debug`.
+
+Most arithmetic functions of the C API take ownership of their arguments, so the
+wrappers below always hand them clones and keep their own handles untouched.
+"""
+
+import ctypes
+import os
+import sys
+from pathlib import Path
+
+__all__ = ["Complex", "Polynomial", "Matrix"]
+
+
+def _library_path():
+    env_path = os.environ.get("COMPLEX_POLYNOMIALS_LIB")
+    if env_path:
+        return env_path
+
+    if sys.platform == "win32":
+        names = ["libcomplex_polynomials.dll"]
+    elif sys.platform == "darwin":
+        names = ["liblibcomplex_polynomials.dylib"]
+    else:
+        names = ["liblibcomplex_polynomials.so"]
+
+    target = Path(__file__).resolve().parent.parent / "rust" / "target"
+    for profile in ("release", "debug"):
+        for name in names:
+            candidate = target / profile / name
+            if candidate.exists():
+                return str(candidate)
+
+    raise OSError(
+        "couldn't find the complex polynomials library, build it with "
+        "`cargo build` or set COMPLEX_POLYNOMIALS_LIB"
+    )
+
+
+class _Complex(ctypes.Structure):
+    _fields_ = [("re", ctypes.c_float), ("im", ctypes.c_float)]
+
+
+_ComplexPtr = ctypes.POINTER(_Complex)
+_PolynomialPtr = ctypes.c_void_p
+_MatrixPtr = ctypes.c_void_p
+
+_lib = ctypes.CDLL(_library_path())
+
+
+def _declare(name, restype, *argtypes):
+    function = getattr(_lib, name)
+    function.restype = restype
+    function.argtypes = list(argtypes)
+
+
+_declare("string_free", None, ctypes.c_void_p)
+
+_declare("complex_new", _ComplexPtr, ctypes.c_float, ctypes.c_float)
+_declare("complex_free", None, _ComplexPtr)
+_declare("complex_add", _ComplexPtr, _ComplexPtr, _ComplexPtr)
+_declare("complex_mul", _ComplexPtr, _ComplexPtr, _ComplexPtr)
+
+_declare("polynomial_from_coefficients", _PolynomialPtr, _ComplexPtr, ctypes.c_size_t)
+_declare("polynomial_clone", _PolynomialPtr, _PolynomialPtr)
+_declare("polynomial_degree", ctypes.c_size_t, _PolynomialPtr)
+_declare("polynomial_coefficients", _ComplexPtr, _PolynomialPtr)
+_declare("polynomial_to_string", ctypes.c_void_p, _PolynomialPtr)
+_declare("polynomial_free", None, _PolynomialPtr)
+_declare("gen_random_polynomial", _PolynomialPtr)
+_declare("gen_random_polynomial_seeded", _PolynomialPtr, ctypes.c_uint64)
+_declare("polynomial_add", _PolynomialPtr, _PolynomialPtr, _PolynomialPtr)
+_declare("polynomial_mul", _PolynomialPtr, _PolynomialPtr, _PolynomialPtr)
+_declare("polynomial_add_in_ring", _PolynomialPtr, _PolynomialPtr, _PolynomialPtr, ctypes.c_size_t)
+_declare("polynomial_mul_in_ring", _PolynomialPtr, _PolynomialPtr, _PolynomialPtr, ctypes.c_size_t)
+_declare("polynomial_neg", _PolynomialPtr, _PolynomialPtr)
+_declare("polynomial_reduce_to", _PolynomialPtr, _PolynomialPtr, ctypes.c_size_t)
+_declare(
+    "polynomial_euclidean_division",
+    None,
+    _PolynomialPtr,
+    _PolynomialPtr,
+    ctypes.POINTER(_PolynomialPtr),
+    ctypes.POINTER(_PolynomialPtr),
+)
+
+_declare(
+    "matrix_from_polynomials",
+    _MatrixPtr,
+    ctypes.POINTER(_PolynomialPtr),
+    ctypes.c_size_t,
+    ctypes.c_size_t,
+)
+_declare("matrix_clone", _MatrixPtr, _MatrixPtr)
+_declare("matrix_width", ctypes.c_size_t, _MatrixPtr)
+_declare("matrix_height", ctypes.c_size_t, _MatrixPtr)
+_declare("matrix_get", _PolynomialPtr, _MatrixPtr, ctypes.c_size_t, ctypes.c_size_t)
+_declare("matrix_to_string", ctypes.c_void_p, _MatrixPtr)
+_declare("matrix_free", None, _MatrixPtr)
+_declare("matrix_add", _MatrixPtr, _MatrixPtr, _MatrixPtr)
+_declare("matrix_mul", _MatrixPtr, _MatrixPtr, _MatrixPtr)
+_declare("matrix_add_in_ring", _MatrixPtr, _MatrixPtr, _MatrixPtr, ctypes.c_size_t)
+_declare("matrix_mul_in_ring", _MatrixPtr, _MatrixPtr, _MatrixPtr, ctypes.c_size_t)
+
+_HAS_PARSE = hasattr(_lib, "polynomial_parse")
+if _HAS_PARSE:
+    _declare("polynomial_parse", _PolynomialPtr, ctypes.c_char_p)
+
+
+def _take_string(pointer):
+    try:
+        return ctypes.string_at(pointer).decode("utf-8")
+    finally:
+        _lib.string_free(pointer)
+
+
+class Complex:
+    """A complex number with single precision real and imaginary parts."""
+
+    __slots__ = ("re", "im")
+
+    def __init__(self, re=0.0, im=0.0):
+        value = _Complex(re, im)
+        self.re = value.re
+        self.im = value.im
+
+    @classmethod
+    def from_complex(cls, value):
+        value = complex(value)
+        return cls(value.real, value.imag)
+
+    @classmethod
+    def _from_ptr(cls, pointer):
+        try:
+            return cls(pointer.contents.re, pointer.contents.im)
+        finally:
+            _lib.complex_free(pointer)
+
+    def _new_ptr(self):
+        return _lib.complex_new(self.re, self.im)
+
+    def __add__(self, other):
+        other = _to_complex(other)
+        return Complex._from_ptr(_lib.complex_add(self._new_ptr(), other._new_ptr()))
+
+    __radd__ = __add__
+
+    def __mul__(self, other):
+        other = _to_complex(other)
+        return Complex._from_ptr(_lib.complex_mul(self._new_ptr(), other._new_ptr()))
+
+    __rmul__ = __mul__
+
+    def __neg__(self):
+        return Complex(-self.re, -self.im)
+
+    def __sub__(self, other):
+        return self + -_to_complex(other)
+
+    def __rsub__(self, other):
+        return _to_complex(other) + -self
+
+    def __complex__(self):
+        return complex(self.re, self.im)
+
+    def __eq__(self, other):
+        try:
+            other = _to_complex(other)
+        except TypeError:
+            return NotImplemented
+        return self.re == other.re and self.im == other.im
+
+    def __hash__(self):
+        return hash(complex(self))
+
+    def __repr__(self):
+        return f"Complex({self.re!r}, {self.im!r})"
+
+
+def _to_complex(value):
+    if isinstance(value, Complex):
+        return value
+    if isinstance(value, (int, float, complex)):
+        return Complex.from_complex(value)
+    raise TypeError(f"can't convert {type(value).__name__} to Complex")
+
+
+class Polynomial:
+    """A polynomial with complex coefficients, stored from lowest to highest degree."""
+
+    __slots__ = ("_ptr",)
+
+    def __init__(self, coefficients=()):
+        coefficients = [_to_complex(c) for c in coefficients]
+        buffer = (_Complex * len(coefficients))(*((c.re, c.im) for c in coefficients))
+        self._ptr = _lib.polynomial_from_coefficients(buffer, len(coefficients))
+
+    @classmethod
+    def _from_ptr(cls, pointer):
+        if not pointer:
+            raise ValueError("the library returned a null polynomial")
+        poly = cls.__new__(cls)
+        poly._ptr = pointer
+        return poly
+
+    @classmethod
+    def parse(cls, text):
+        """Parse a polynomial such as `(1.5 + 2i)X2 + (-1 + 0i)`.
+
+        Requires the library to be built with the `parse` feature.
+        """
+        if not _HAS_PARSE:
+            raise NotImplementedError("the library was built without the `parse` feature")
+        pointer = _lib.polynomial_parse(text.encode("utf-8"))
+        if not pointer:
+            raise ValueError(f"invalid polynomial: {text!r}")
+        return cls._from_ptr(pointer)
+
+    @classmethod
+    def random(cls, seed=None):
+        """Generate a random polynomial, reproducible across platforms when `seed` is given."""
+        if seed is None:
+            return cls._from_ptr(_lib.gen_random_polynomial())
+        return cls._from_ptr(_lib.gen_random_polynomial_seeded(seed))
+
+    def _clone_ptr(self):
+        return _lib.polynomial_clone(self._ptr)
+
+    @property
+    def degree(self):
+        return _lib.polynomial_degree(self._ptr)
+
+    def to_list(self):
+        """Returns the coefficients as Python `complex` values, from lowest to highest degree."""
+        coefficients = _lib.polynomial_coefficients(self._ptr)
+        return [
+            complex(coefficients[i].re, coefficients[i].im) for i in range(self.degree + 1)
+        ]
+
+    @classmethod
+    def from_list(cls, coefficients):
+        return cls(coefficients)
+
+    def __add__(self, other):
+        other = _to_polynomial(other)
+        return Polynomial._from_ptr(_lib.polynomial_add(self._clone_ptr(), other._clone_ptr()))
+
+    __radd__ = __add__
+
+    def __mul__(self, other):
+        other = _to_polynomial(other)
+        return Polynomial._from_ptr(_lib.polynomial_mul(self._clone_ptr(), other._clone_ptr()))
+
+    __rmul__ = __mul__
+
+    def __neg__(self):
+        return Polynomial._from_ptr(_lib.polynomial_neg(self._clone_ptr()))
+
+    def __sub__(self, other):
+        return self + -_to_polynomial(other)
+
+    def __rsub__(self, other):
+        return _to_polynomial(other) + -self
+
+    def add_in_ring(self, other, ring):
+        other = _to_polynomial(other)
+        return Polynomial._from_ptr(
+            _lib.polynomial_add_in_ring(self._clone_ptr(), other._clone_ptr(), ring)
+        )
+
+    def mul_in_ring(self, other, ring):
+        other = _to_polynomial(other)
+        return Polynomial._from_ptr(
+            _lib.polynomial_mul_in_ring(self._clone_ptr(), other._clone_ptr(), ring)
+        )
+
+    def reduce_to(self, ring):
+        return Polynomial._from_ptr(_lib.polynomial_reduce_to(self._clone_ptr(), ring))
+
+    def euclidean_division(self, denominator):
+        """Returns the (quotient, remainder) of the euclidean division by `denominator`."""
+        denominator = _to_polynomial(denominator)
+        quotient = _PolynomialPtr()
+        remainder = _PolynomialPtr()
+        _lib.polynomial_euclidean_division(
+            self._clone_ptr(),
+            denominator._clone_ptr(),
+            ctypes.byref(quotient),
+            ctypes.byref(remainder),
+        )
+        return Polynomial._from_ptr(quotient.value), Polynomial._from_ptr(remainder.value)
+
+    def __eq__(self, other):
+        try:
+            other = _to_polynomial(other)
+        except TypeError:
+            return NotImplemented
+        return self.to_list() == other.to_list()
+
+    __hash__ = None
+
+    def __str__(self):
+        return _take_string(_lib.polynomial_to_string(self._ptr))
+
+    def __repr__(self):
+        return f"Polynomial({self.to_list()!r})"
+
+    def __del__(self):
+        pointer = getattr(self, "_ptr", None)
+        if pointer:
+            _lib.polynomial_free(pointer)
+            self._ptr = None
+
+
+def _to_polynomial(value):
+    if isinstance(value, Polynomial):
+        return value
+    if isinstance(value, (Complex, int, float, complex)):
+        return Polynomial([value])
+    if isinstance(value, (list, tuple)):
+        return Polynomial(value)
+    raise TypeError(f"can't convert {type(value).__name__} to Polynomial")
+
+
+class Matrix:
+    """A row-major matrix of polynomials."""
+
+    __slots__ = ("_ptr",)
+
+    def __init__(self, rows):
+        """Create a matrix from a list of rows, each entry being a `Polynomial` or a list
+        of coefficients."""
+        rows = [[_to_polynomial(entry) for entry in row] for row in rows]
+        height = len(rows)
+        width = len(rows[0]) if rows else 0
+        if width == 0 or any(len(row) != width for row in rows):
+            raise ValueError("a matrix needs non-empty rows of the same length")
+
+        entries = [entry for row in rows for entry in row]
+        pointers = (_PolynomialPtr * len(entries))(*(entry._ptr for entry in entries))
+        self._ptr = _lib.matrix_from_polynomials(pointers, width, height)
+
+    @classmethod
+    def _from_ptr(cls, pointer):
+        if not pointer:
+            raise ValueError("the library returned a null matrix")
+        matrix = cls.__new__(cls)
+        matrix._ptr = pointer
+        return matrix
+
+    def _clone_ptr(self):
+        return _lib.matrix_clone(self._ptr)
+
+    @property
+    def width(self):
+        return _lib.matrix_width(self._ptr)
+
+    @property
+    def height(self):
+        return _lib.matrix_height(self._ptr)
+
+    @property
+    def shape(self):
+        """Returns (height, width)."""
+        return self.height, self.width
+
+    def __getitem__(self, index):
+        row, column = index
+        if not (0 <= row < self.height and 0 <= column < self.width):
+            raise IndexError(f"index {index} out of range for shape {self.shape}")
+        return Polynomial._from_ptr(_lib.matrix_get(self._ptr, row, column))
+
+    def rows(self):
+        """Returns the entries as a list of rows of `Polynomial`."""
+        return [[self[i, j] for j in range(self.width)] for i in range(self.height)]
+
+    def to_list(self):
+        """Returns the entries as a list of rows of coefficient lists of Python `complex`."""
+        return [[entry.to_list() for entry in row] for row in self.rows()]
+
+    @classmethod
+    def from_list(cls, rows):
+        return cls(rows)
+
+    def _check_same_shape(self, other):
+        if self.shape != other.shape:
+            raise ValueError(f"shape mismatch: {self.shape} and {other.shape}")
+
+    def _check_mul_shape(self, other):
+        if self.width != other.height:
+            raise ValueError(f"can't multiply shapes {self.shape} and {other.shape}")
+
+    def __add__(self, other):
+        if not isinstance(other, Matrix):
+            return NotImplemented
+        self._check_same_shape(other)
+        return Matrix._from_ptr(_lib.matrix_add(self._clone_ptr(), other._clone_ptr()))
+
+    def __matmul__(self, other):
+        if not isinstance(other, Matrix):
+            return NotImplemented
+        self._check_mul_shape(other)
+        return Matrix._from_ptr(_lib.matrix_mul(self._clone_ptr(), other._clone_ptr()))
+
+    __mul__ = __matmul__
+
+    def add_in_ring(self, other, ring):
+        self._check_same_shape(other)
+        return Matrix._from_ptr(
+            _lib.matrix_add_in_ring(self._clone_ptr(), other._clone_ptr(), ring)
+        )
+
+    def mul_in_ring(self, other, ring):
+        self._check_mul_shape(other)
+        return Matrix._from_ptr(
+            _lib.matrix_mul_in_ring(self._clone_ptr(), other._clone_ptr(), ring)
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, Matrix):
+            return NotImplemented
+        return self.to_list() == other.to_list()
+
+    __hash__ = None
+
+    def __str__(self):
+        return _take_string(_lib.matrix_to_string(self._ptr))
+
+    def __repr__(self):
+        return f"Matrix({self.to_list()!r})"
+
+    def __del__(self):
+        pointer = getattr(self, "_ptr", None)
+        if pointer:
+            _lib.matrix_free(pointer)
+            self._ptr = None
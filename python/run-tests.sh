@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+
+set -xeE
+
+(cd ../rust && cargo build --release)
+python3 -m unittest -v test_complex_polynomials
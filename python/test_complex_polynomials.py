@@ -0,0 +1,137 @@
+import unittest
+
+from complex_polynomials import Complex, Matrix, Polynomial
+
+
+class ComplexTest(unittest.TestCase):
+    def test_arithmetic(self):
+        c1 = Complex(2.0, 5.0)
+        c2 = Complex(-1.0, 10.0)
+
+        self.assertEqual(Complex(1.0, 15.0), c1 + c2)
+        self.assertEqual(Complex(-52.0, 15.0), c1 * c2)
+        self.assertEqual(Complex(3.0, -5.0), c1 - c2)
+        self.assertEqual(Complex(-2.0, -5.0), -c1)
+
+    def test_python_complex_conversion(self):
+        c = Complex.from_complex(3 - 4j)
+
+        self.assertEqual(Complex(3.0, -4.0), c)
+        self.assertEqual(3 - 4j, complex(c))
+        self.assertEqual(Complex(4.0, -4.0), c + 1)
+        self.assertEqual(Complex(6.0, -8.0), 2 * c)
+
+
+class PolynomialTest(unittest.TestCase):
+    def test_list_conversion(self):
+        p = Polynomial([1, 2j, 3 - 1j])
+
+        self.assertEqual(2, p.degree)
+        self.assertEqual([1 + 0j, 2j, 3 - 1j], p.to_list())
+        self.assertEqual(p, Polynomial.from_list(p.to_list()))
+        self.assertEqual(0, Polynomial().degree)
+
+    def test_add(self):
+        p1 = Polynomial([3, 1])
+        p2 = Polynomial([1, -1])
+
+        self.assertEqual(Polynomial([4]), p1 + p2)
+        self.assertEqual(Polynomial([4]), p1.add_in_ring(p2, 1))
+        self.assertEqual(Polynomial([2, 2]), p1 - p2)
+        self.assertEqual(Polynomial([-3, -1]), -p1)
+
+    def test_mul(self):
+        p1 = Polynomial([-3, 0, 3])
+        p2 = Polynomial([0, 2, -1])
+
+        self.assertEqual(Polynomial([0, -6, 3, 6, -3]), p1 * p2)
+        self.assertEqual(Polynomial([-3, -6, 3, 6]), p1.mul_in_ring(p2, 4))
+        self.assertEqual(Polynomial([6, -9, 3]), p1.mul_in_ring(p2, 3))
+
+    def test_operands_are_left_untouched(self):
+        p1 = Polynomial([3, 1])
+        p2 = Polynomial([1, -1])
+
+        for _ in range(3):
+            p1 + p2
+            p1 * p2
+
+        self.assertEqual([3, 1], p1.to_list())
+        self.assertEqual([1, -1], p2.to_list())
+
+    def test_reduce_and_division(self):
+        p = Polynomial([1, 0, -1, 0, 2, 1])
+
+        self.assertEqual(Polynomial([3, 1, -1]), p.reduce_to(4))
+
+        quotient, remainder = Polynomial([1, 0, -1, 0, 0, 1]).euclidean_division(
+            Polynomial([-1, 0, 1])
+        )
+        self.assertEqual(Polynomial([-1, 1, 0, 1]), quotient)
+        self.assertEqual(Polynomial([0, 1]), remainder)
+
+    def test_parse(self):
+        p = Polynomial.parse("(1.5 + 2i)X2 + (-1 - 0.5i)")
+
+        self.assertEqual([-1 - 0.5j, 0j, 1.5 + 2j], p.to_list())
+        self.assertEqual("(1.5 + 2i)X2 + -1 + -0.5i", str(p))
+
+        with self.assertRaises(ValueError):
+            Polynomial.parse("not a polynomial")
+
+    def test_random(self):
+        p = Polynomial.random()
+
+        self.assertTrue(1 <= p.degree <= 3)
+        self.assertEqual(Polynomial.random(seed=7), Polynomial.random(seed=7))
+
+        expected = [9.0055065 + 5.4462643j, 2.5472088 + 4.74312j]
+        for e, c in zip(expected, Polynomial.random(seed=42).to_list(), strict=True):
+            self.assertAlmostEqual(e, c, places=5)
+
+
+class MatrixTest(unittest.TestCase):
+    def setUp(self):
+        self.m1 = Matrix([[[1, 1], [2]], [[0, 1], [1]]])
+        self.m2 = Matrix([[[1], [0]], [[0], [1]]])
+
+    def test_list_conversion(self):
+        self.assertEqual((2, 2), self.m1.shape)
+        self.assertEqual(Polynomial([2]), self.m1[0, 1])
+        self.assertEqual([[[1, 1], [2]], [[0, 1], [1]]], self.m1.to_list())
+        self.assertEqual(self.m1, Matrix.from_list(self.m1.to_list()))
+
+        with self.assertRaises(IndexError):
+            self.m1[2, 0]
+
+        with self.assertRaises(ValueError):
+            Matrix([[[1]], [[1], [2]]])
+
+    def test_add(self):
+        self.assertEqual(Matrix([[[2, 1], [2]], [[0, 1], [2]]]), self.m1 + self.m2)
+        self.assertEqual(
+            Matrix([[[2, 1], [2]], [[0, 1], [2]]]), self.m1.add_in_ring(self.m2, 2)
+        )
+
+        with self.assertRaises(ValueError):
+            self.m1 + Matrix([[[1]]])
+
+    def test_mul(self):
+        self.assertEqual(self.m1, self.m1 @ self.m2)
+        self.assertEqual(self.m1, self.m1 * self.m2)
+
+        squared = Matrix([[[1, 4, 1], [4, 2]], [[0, 2, 1], [1, 2]]])
+        self.assertEqual(squared, self.m1 @ self.m1)
+        self.assertEqual(
+            Matrix([[[2, 4], [4, 2]], [[1, 2], [1, 2]]]), self.m1.mul_in_ring(self.m1, 2)
+        )
+
+        with self.assertRaises(ValueError):
+            self.m1 @ Matrix([[[1], [1]]])
+
+    def test_display(self):
+        self.assertEqual("[(1)X + 1, 2]\n[(1)X, 1]", str(self.m1))
+
+
+if __name__ == "__main__":
+    unittest.main()
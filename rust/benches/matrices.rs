@@ -2,8 +2,12 @@
 extern crate bencher;
 
 use bencher::Bencher;
-use libcomplex_polynomials::{complex::Complex, matrix::Matrix, polynomial::Polynomial};
-use rand::thread_rng;
+use libcomplex_polynomials::{
+    complex::Complex,
+    matrix::{Matrix, STRASSEN_CROSSOVER},
+    polynomial::Polynomial,
+    random,
+};
 
 fn matrices_add_regular(bench: &mut Bencher) {
     let p1 = Polynomial::new(vec![
@@ -39,7 +43,7 @@ fn matrices_add_regular(bench: &mut Bencher) {
 }
 
 fn matrices_add_regular_big(bench: &mut Bencher) {
-    let mut rand = thread_rng();
+    let mut rand = random::seeded(0);
 
     let m1 = Matrix::random(8, 8, &mut rand);
     let m2 = Matrix::random(8, 8, &mut rand);
@@ -115,6 +119,39 @@ fn matrices_mul_regular(bench: &mut Bencher) {
     });
 }
 
+fn matrices_mul_regular_big(bench: &mut Bencher) {
+    let mut rand = random::seeded(0);
+
+    let m1 = Matrix::random(64, 64, &mut rand);
+    let m2 = Matrix::random(64, 64, &mut rand);
+
+    bench.iter(|| {
+        Matrix::mul(&m1, &m2);
+    });
+}
+
+fn matrices_mul_regular_huge(bench: &mut Bencher) {
+    let mut rand = random::seeded(0);
+
+    let m1 = Matrix::random(256, 256, &mut rand);
+    let m2 = Matrix::random(256, 256, &mut rand);
+
+    bench.iter(|| {
+        Matrix::mul(&m1, &m2);
+    });
+}
+
+fn matrices_mul_strassen_big(bench: &mut Bencher) {
+    let mut rand = random::seeded(0);
+
+    let m1 = Matrix::random(128, 128, &mut rand);
+    let m2 = Matrix::random(128, 128, &mut rand);
+
+    bench.iter(|| {
+        Matrix::mul_strassen(&m1, &m2, STRASSEN_CROSSOVER);
+    });
+}
+
 fn matrices_mul_in_ring(bench: &mut Bencher) {
     let p1 = Polynomial::new(vec![
         Complex::new(1.0, 2.0),
@@ -154,6 +191,9 @@ benchmark_group!(
     matrices_add_regular_big,
     matrices_add_in_ring,
     matrices_mul_regular,
+    matrices_mul_regular_big,
+    matrices_mul_regular_huge,
+    matrices_mul_strassen_big,
     matrices_mul_in_ring
 );
 benchmark_main!(benches);
@@ -0,0 +1,321 @@
+//! Approximate equality of floats, complex numbers, polynomials, matrices and slices.
+//!
+//! [`Complex`] compares the bits of its parts, so results that only differ by rounding
+//! errors aren't `==`. [`ApproxEq`] compares them within a [`Tolerance`] instead, and the
+//! [`assert_approx_eq!`](crate::assert_approx_eq) and
+//! [`assert_approx_ne!`](crate::assert_approx_ne) macros are its counterparts of
+//! `assert_eq!` and `assert_ne!`:
+//!
+//! ```
+//! use libcomplex_polynomials::{approx::Tolerance, assert_approx_eq, complex::Complex};
+//!
+//! let tenth = Complex::new(0.1, 0.0);
+//! let sum = (0..10).fold(Complex::ZERO, |acc, _| acc + tenth);
+//! assert_ne!(Complex::new(1.0, 0.0), sum);
+//! assert_approx_eq!(Complex::new(1.0, 0.0), sum);
+//! assert_approx_eq!(
+//!     Complex::new(1.0, 0.0),
+//!     Complex::new(1.001, 0.0),
+//!     Tolerance::new().relative(1e-2)
+//! );
+//! ```
+
+use crate::{complex::Complex, element::Element, matrix::Matrix, polynomial::Polynomial};
+
+/// How far apart two floats can be while being approximately equal, built with chained
+/// setters. Floats are approximately equal if any of the three tolerances accepts them:
+///
+/// - their difference is at most `absolute`, which is the only one that works near zero,
+/// - their difference is at most `relative` times the largest of their magnitudes,
+/// - they are at most `ulps` floats apart, in units in the last place.
+///
+/// The default tolerance accepts differences of `1e-6`, relative differences of `1e-5`, or
+/// 4 ULPs. NaNs are never approximately equal to anything.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerance {
+    absolute: f32,
+    relative: f32,
+    ulps: u32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance {
+            absolute: 1e-6,
+            relative: 1e-5,
+            ulps: 4,
+        }
+    }
+}
+
+impl Tolerance {
+    pub fn new() -> Tolerance {
+        Tolerance::default()
+    }
+
+    /// A tolerance that only accepts equal floats.
+    pub fn exact() -> Tolerance {
+        Tolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            ulps: 0,
+        }
+    }
+
+    /// Set the largest accepted difference.
+    pub fn absolute(mut self, absolute: f32) -> Tolerance {
+        assert!(absolute >= 0.0, "tolerances must be non-negative");
+
+        self.absolute = absolute;
+        self
+    }
+
+    /// Set the largest accepted difference, relative to the largest magnitude of the two
+    /// values.
+    pub fn relative(mut self, relative: f32) -> Tolerance {
+        assert!(relative >= 0.0, "tolerances must be non-negative");
+
+        self.relative = relative;
+        self
+    }
+
+    /// Set the largest accepted number of units in the last place between the two values.
+    pub fn ulps(mut self, ulps: u32) -> Tolerance {
+        self.ulps = ulps;
+        self
+    }
+
+    /// Returns whether floats whose difference has magnitude `difference` and which have
+    /// magnitudes up to `magnitude` are close enough, according to the absolute and
+    /// relative tolerances. An infinite difference is never accepted, even relatively to an
+    /// infinite magnitude.
+    fn accepts(&self, difference: f32, magnitude: f32) -> bool {
+        difference.is_finite()
+            && (difference <= self.absolute || difference <= self.relative * magnitude)
+    }
+}
+
+/// Values that can be compared within a [`Tolerance`].
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        // Equal infinities have an infinite difference.
+        if self == other {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+
+        tolerance.accepts((self - other).abs(), self.abs().max(other.abs()))
+            || ulps_between(*self, *other) <= tolerance.ulps as u64
+    }
+}
+
+/// Complex numbers are compared by the modulus of their difference, so that the rounding
+/// errors of a tiny part are measured against the modulus of the whole number. The ULPs are
+/// counted part by part.
+impl ApproxEq for Complex {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        if self == other {
+            return true;
+        }
+        if [self.re, self.im, other.re, other.im]
+            .iter()
+            .any(|x| x.is_nan())
+        {
+            return false;
+        }
+
+        tolerance.accepts((self - other).norm(), self.norm().max(other.norm()))
+            || (ulps_between(self.re, other.re) <= tolerance.ulps as u64
+                && ulps_between(self.im, other.im) <= tolerance.ulps as u64)
+    }
+}
+
+/// Polynomials are compared coefficient by coefficient, the missing coefficients of the one
+/// of lower degree being zeros: leading coefficients that are rounding errors don't make
+/// polynomials different, as long as the absolute tolerance accepts them.
+impl<C: Element + ApproxEq> ApproxEq for Polynomial<C> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        let zero = C::zero();
+        let len = self.coefficients.len().max(other.coefficients.len());
+        let padded = |p: &Polynomial<C>| {
+            let padding = len - p.coefficients.len();
+            p.coefficients
+                .clone()
+                .into_iter()
+                .chain(vec![zero.clone(); padding])
+        };
+
+        padded(self)
+            .zip(padded(other))
+            .all(|(a, b)| a.approx_eq(&b, tolerance))
+    }
+}
+
+/// Matrices are approximately equal if they have the same size and all their elements are.
+impl<E: ApproxEq> ApproxEq for Matrix<E> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self
+                .content
+                .iter()
+                .zip(&other.content)
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+/// Slices are approximately equal if they have the same length and all their elements are.
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+/// Returns how many floats apart `a` and `b` are, 1 for consecutive floats.
+fn ulps_between(a: f32, b: f32) -> u64 {
+    // Map the floats to integers in the same order: non-negative floats are already ordered
+    // like their bits, negative ones in reverse since their sign bit is set.
+    let ordered = |x: f32| {
+        let bits = x.to_bits() as i32 as i64;
+        if bits < 0 {
+            i32::MIN as i64 - bits
+        } else {
+            bits
+        }
+    };
+
+    ordered(a).abs_diff(ordered(b))
+}
+
+/// Asserts that two values are approximately equal with [`ApproxEq`], within the default
+/// [`Tolerance`] or the provided one.
+///
+/// [`ApproxEq`]: crate::approx::ApproxEq
+/// [`Tolerance`]: crate::approx::Tolerance
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n within: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values aren't approximately equal with [`ApproxEq`], within the
+/// default [`Tolerance`] or the provided one.
+///
+/// [`ApproxEq`]: crate::approx::ApproxEq
+/// [`Tolerance`]: crate::approx::Tolerance
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≉ right` failed\n  left: {:?}\n right: {:?}\n within: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floats() {
+        let exact = Tolerance::exact();
+        assert!(1.0f32.approx_eq(&1.0, &exact));
+        assert!(!1.0f32.approx_eq(&(1.0 + f32::EPSILON), &exact));
+        assert!(f32::INFINITY.approx_eq(&f32::INFINITY, &exact));
+        assert!(!f32::NAN.approx_eq(&f32::NAN, &Tolerance::new()));
+
+        // ULPs are counted across zero, and between the largest float and infinity.
+        let ulps = Tolerance::exact().ulps(2);
+        assert!(1.0f32.approx_eq(&(1.0 + 2.0 * f32::EPSILON), &ulps));
+        assert!(!1.0f32.approx_eq(&(1.0 + 4.0 * f32::EPSILON), &ulps));
+        let tiny = f32::from_bits(1);
+        assert!(tiny.approx_eq(&-tiny, &ulps));
+        assert!(!tiny.approx_eq(&-f32::from_bits(2), &ulps));
+        assert!(f32::MAX.approx_eq(&f32::INFINITY, &Tolerance::exact().ulps(1)));
+
+        // Relative tolerances scale with the values, absolute ones don't.
+        let relative = Tolerance::exact().relative(1e-3);
+        assert!(1000.0f32.approx_eq(&1000.9, &relative));
+        assert!(!1e-9f32.approx_eq(&0.0, &relative));
+        assert!(1e-9f32.approx_eq(&0.0, &Tolerance::exact().absolute(1e-8)));
+        assert!(!1000.0f32.approx_eq(&1000.9, &Tolerance::exact().absolute(0.5)));
+    }
+
+    #[test]
+    fn test_complex_polynomials_matrices() {
+        // The rounding error of the tiny imaginary part is small next to the modulus.
+        let a = Complex::new(1000.0, 1e-3);
+        let b = Complex::new(1000.0, 1.1e-3);
+        assert!(a.approx_eq(&b, &Tolerance::exact().relative(1e-6)));
+        assert!(!1e-3f32.approx_eq(&1.1e-3, &Tolerance::exact().relative(1e-6)));
+
+        let tenth = Complex::new(0.1, -0.2);
+        let sum = (0..10).fold(Complex::ZERO, |acc, _| acc + tenth);
+        assert_ne!(Complex::new(1.0, -2.0), sum);
+        assert_approx_eq!(Complex::new(1.0, -2.0), sum);
+
+        // Leading rounding errors don't change the polynomial.
+        let p = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+        let q = Polynomial::new(vec![sum, Complex::new(2.0, 1e-7), Complex::new(1e-8, 0.0)]);
+        assert_approx_ne!(p, q);
+        assert_approx_eq!(
+            Polynomial::new(vec![Complex::new(1.0, -2.0), Complex::new(2.0, 0.0)]),
+            q
+        );
+        assert_approx_ne!(q, Polynomial::new(vec![Complex::new(1.0, -2.0)]));
+
+        let m = Matrix::new(vec![sum; 4], 2, 2);
+        assert_approx_eq!(Matrix::new(vec![Complex::new(1.0, -2.0); 4], 2, 2), m);
+        assert_approx_ne!(Matrix::new(vec![Complex::new(1.0, -2.0); 4], 4, 1), m);
+
+        // Slices and vectors compare element by element, and need the same length.
+        assert_approx_eq!(vec![Complex::new(1.0, -2.0); 3], vec![sum; 3]);
+        assert_approx_ne!(vec![Complex::new(1.0, -2.0); 3], vec![sum; 2]);
+        assert_approx_eq!([1.0f32, 2.0][..], [1.0 + f32::EPSILON, 2.0][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left ≈ right` failed")]
+    fn test_assert_approx_eq() {
+        assert_approx_eq!(1.0f32, 1.1f32, Tolerance::new().relative(0.01));
+    }
+}
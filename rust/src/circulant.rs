@@ -0,0 +1,336 @@
+//! Conversions between ring polynomials and circulant matrices, and circulant solvers.
+//!
+//! Multiplying by `a` in `C[x]/(x^n - 1)` is multiplying the coefficient vector by the `n`x`n`
+//! circulant matrix of `a`, whose columns are the successive rotations of the coefficients of
+//! `a`. In `C[x]/(x^n + 1)`, the rotations negate the coefficients wrapping around, which
+//! gives a skew-circulant (or negacyclic) matrix.
+
+use std::f64::consts::PI;
+
+use crate::{
+    complex::Complex, complex64::C64, dft, error::Error, matrix::Matrix, polynomial::Polynomial,
+};
+
+/// Returns the coefficients of `p` reduced modulo `x^ring - 1`, or `x^ring + 1` if
+/// `negacyclic`, as exactly `ring` values.
+fn wrapped_coefficients(p: &Polynomial, ring: usize, negacyclic: bool) -> Vec<Complex> {
+    let mut coefficients = vec![Complex::ZERO; ring];
+
+    for (k, c) in p.coefficients.iter().enumerate() {
+        // x^ring = -1 in the negacyclic ring, so every wrap flips the sign.
+        let sign_flips = negacyclic && (k / ring) % 2 == 1;
+
+        coefficients[k % ring] = if sign_flips {
+            coefficients[k % ring] - *c
+        } else {
+            coefficients[k % ring] + *c
+        };
+    }
+
+    coefficients
+}
+
+/// Returns the `ring`x`ring` matrix whose element at (`i`, `j`) is the coefficient of degree
+/// `i - j` of `p`, the one of degree `ring + i - j` when `i < j`, negated if `negacyclic`.
+fn circulant(p: &Polynomial, ring: usize, negacyclic: bool) -> Result<Matrix<Complex>, Error> {
+    if ring == 0 {
+        return Err(Error::InvalidRing(ring));
+    }
+
+    let coefficients = wrapped_coefficients(p, ring, negacyclic);
+    let mut content = Vec::with_capacity(ring * ring);
+
+    for i in 0..ring {
+        for j in 0..ring {
+            content.push(if i >= j {
+                coefficients[i - j]
+            } else if negacyclic {
+                coefficients[ring + i - j].neg()
+            } else {
+                coefficients[ring + i - j]
+            });
+        }
+    }
+
+    Ok(Matrix::new(content, ring, ring))
+}
+
+impl Polynomial {
+    /// Returns the circulant matrix of the polynomial in the ring of the provided degree:
+    /// multiplying it by the coefficients of `b` gives the coefficients of
+    /// `Polynomial::mul_in_ring(self, b, ring)`.
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_to_circulant`].
+    pub fn to_circulant(&self, ring: usize) -> Matrix<Complex> {
+        self.try_to_circulant(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the circulant matrix of the polynomial in the ring of the provided degree, or
+    /// an error if `ring` is 0.
+    pub fn try_to_circulant(&self, ring: usize) -> Result<Matrix<Complex>, Error> {
+        circulant(self, ring, false)
+    }
+
+    /// Returns the skew-circulant matrix of the polynomial modulo `x^ring + 1`: multiplying
+    /// it by the coefficients of `b` gives the coefficients of `self * b` modulo
+    /// `x^ring + 1`.
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_to_negacyclic`].
+    pub fn to_negacyclic(&self, ring: usize) -> Matrix<Complex> {
+        self.try_to_negacyclic(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the skew-circulant matrix of the polynomial modulo `x^ring + 1`, or an error
+    /// if `ring` is 0.
+    pub fn try_to_negacyclic(&self, ring: usize) -> Result<Matrix<Complex>, Error> {
+        circulant(self, ring, true)
+    }
+}
+
+impl Matrix<Complex> {
+    /// Returns the polynomial whose circulant matrix this is, the inverse of
+    /// [`Polynomial::to_circulant`] with the width of the matrix as ring degree.
+    ///
+    /// Panics if the matrix isn't circulant, see [`Matrix::try_circulant_to_polynomial`].
+    pub fn circulant_to_polynomial(&self) -> Polynomial {
+        self.try_circulant_to_polynomial()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the polynomial whose circulant matrix this is, or an error if the matrix isn't
+    /// square or circulant. Elements are compared exactly.
+    pub fn try_circulant_to_polynomial(&self) -> Result<Polynomial, Error> {
+        self.structured_to_polynomial(false)
+    }
+
+    /// Returns the polynomial whose skew-circulant matrix this is, the inverse of
+    /// [`Polynomial::to_negacyclic`].
+    ///
+    /// Panics if the matrix isn't skew-circulant, see
+    /// [`Matrix::try_negacyclic_to_polynomial`].
+    pub fn negacyclic_to_polynomial(&self) -> Polynomial {
+        self.try_negacyclic_to_polynomial()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the polynomial whose skew-circulant matrix this is, or an error if the matrix
+    /// isn't square or skew-circulant. Elements are compared exactly.
+    pub fn try_negacyclic_to_polynomial(&self) -> Result<Polynomial, Error> {
+        self.structured_to_polynomial(true)
+    }
+
+    fn structured_to_polynomial(&self, negacyclic: bool) -> Result<Polynomial, Error> {
+        if self.width != self.height {
+            return Err(Error::NotSquare((self.width, self.height)));
+        }
+
+        // The first column holds the coefficients.
+        let coefficients: Vec<Complex> = self.columns().next().unwrap().copied().collect();
+        let polynomial = Polynomial::new(coefficients).trim();
+
+        if circulant(&polynomial, self.width, negacyclic)? != *self {
+            return Err(Error::NotCirculant);
+        }
+
+        Ok(polynomial)
+    }
+
+    /// Solve `CX = B` for `X`, `C` being the circulant matrix of `c` in the ring of degree
+    /// the height of `B`, with DFTs in `O(n log n)` per column for power-of-two sizes.
+    ///
+    /// Panics if `C` is singular, see [`Matrix::try_solve_circulant`].
+    pub fn solve_circulant(c: &Polynomial, b: &Matrix<Complex>) -> Matrix<Complex> {
+        Self::try_solve_circulant(c, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Solve `CX = B` for `X`, `C` being the circulant matrix of `c`, returning an error if
+    /// `C` is singular.
+    pub fn try_solve_circulant(
+        c: &Polynomial,
+        b: &Matrix<Complex>,
+    ) -> Result<Matrix<Complex>, Error> {
+        solve_structured(c, b, false)
+    }
+
+    /// Solve `CX = B` for `X`, `C` being the skew-circulant matrix of `c` modulo
+    /// `x^n + 1`, `n` being the height of `B`.
+    ///
+    /// Panics if `C` is singular, see [`Matrix::try_solve_negacyclic`].
+    pub fn solve_negacyclic(c: &Polynomial, b: &Matrix<Complex>) -> Matrix<Complex> {
+        Self::try_solve_negacyclic(c, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Solve `CX = B` for `X`, `C` being the skew-circulant matrix of `c`, returning an error
+    /// if `C` is singular.
+    pub fn try_solve_negacyclic(
+        c: &Polynomial,
+        b: &Matrix<Complex>,
+    ) -> Result<Matrix<Complex>, Error> {
+        solve_structured(c, b, true)
+    }
+}
+
+/// Solve `CX = B` by dividing the DFTs of the columns of `B` by the DFT of `c`: circulant
+/// matrices are diagonalized by the DFT.
+///
+/// The negacyclic case is brought back to the cyclic one by scaling the coefficient of
+/// degree `k` by `ψ^k`, `ψ` being a primitive `2n`-th root of unity: `x^n + 1` becomes
+/// `x^n - 1` under the substitution `x -> ψx`.
+fn solve_structured(
+    c: &Polynomial,
+    b: &Matrix<Complex>,
+    negacyclic: bool,
+) -> Result<Matrix<Complex>, Error> {
+    let n = b.height;
+    let twist = |k: usize, sign: f64| {
+        if negacyclic {
+            let angle = sign * PI * k as f64 / n as f64;
+            C64::new(angle.cos(), angle.sin())
+        } else {
+            C64::ONE
+        }
+    };
+
+    let mut values: Vec<C64> = wrapped_coefficients(c, n, negacyclic)
+        .into_iter()
+        .enumerate()
+        .map(|(k, c)| C64::from(c) * twist(k, 1.0))
+        .collect();
+    dft::transform(&mut values, 1.0);
+
+    let largest = values.iter().map(|v| v.norm()).fold(0.0, f64::max);
+    if values
+        .iter()
+        .any(|v| v.norm() <= largest * n as f64 * f32::EPSILON as f64)
+    {
+        return Err(Error::NotInvertible);
+    }
+
+    let mut columns = Vec::with_capacity(b.width);
+    for column in b.columns() {
+        let mut x: Vec<C64> = column
+            .enumerate()
+            .map(|(k, c)| C64::from(*c) * twist(k, 1.0))
+            .collect();
+
+        dft::transform(&mut x, 1.0);
+        for (x, v) in x.iter_mut().zip(&values) {
+            *x = *x / *v;
+        }
+        dft::transform(&mut x, -1.0);
+
+        columns.push(
+            x.into_iter()
+                .enumerate()
+                .map(|(k, x)| (x * twist(k, -1.0)).scale(1.0 / n as f64).into())
+                .collect::<Vec<Complex>>(),
+        );
+    }
+
+    let content = (0..n)
+        .flat_map(|i| columns.iter().map(move |column| column[i]))
+        .collect();
+
+    Ok(Matrix::new(content, b.width, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{approx::Tolerance, assert_approx_eq, random, vector::Vector};
+
+    /// Returns the `ring` coefficients of `p`, zero-padded.
+    fn padded(p: &Polynomial, ring: usize) -> Vec<Complex> {
+        let mut coefficients = p.coefficients.clone();
+        coefficients.resize(ring, Complex::ZERO);
+        coefficients
+    }
+
+    #[test]
+    fn test_circulant() {
+        let mut rand = random::seeded(11);
+        let a = Polynomial::random(&mut rand);
+        let b = Polynomial::random(&mut rand);
+        let ring = 5;
+
+        let c = a.to_circulant(ring);
+        let product = c.mul_vector(&Vector::new(padded(&b, ring)));
+        assert_approx_eq!(
+            padded(&Polynomial::mul_in_ring(&a, &b, ring), ring),
+            product.content,
+            Tolerance::new().absolute(1e-3)
+        );
+
+        assert_eq!(a.reduce_to(ring), c.circulant_to_polynomial());
+
+        let mut not_circulant = Matrix::<Complex>::identity(3);
+        not_circulant[(0, 1)] = Complex::new(1.0, 0.0);
+        assert_eq!(
+            Err(Error::NotCirculant),
+            not_circulant.try_circulant_to_polynomial()
+        );
+        assert_eq!(Err(Error::InvalidRing(0)), a.try_to_circulant(0));
+    }
+
+    #[test]
+    fn test_negacyclic() {
+        // x * (1 + 2x + 3x2) = -3 + x + 2x2 modulo x3 + 1
+        let p = Polynomial::new(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+        ]);
+        let c = p.to_negacyclic(3);
+        let x = Vector::new(vec![Complex::ZERO, Complex::new(1.0, 0.0), Complex::ZERO]);
+
+        assert_eq!(
+            vec![
+                Complex::new(-3.0, 0.0),
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 0.0)
+            ],
+            c.mul_vector(&x).content
+        );
+        assert_eq!(p, c.negacyclic_to_polynomial());
+        assert_eq!(
+            Err(Error::NotCirculant),
+            p.to_circulant(3).try_negacyclic_to_polynomial()
+        );
+
+        // x^3 wraps to -1.
+        let cube = Polynomial::new(vec![
+            Complex::ZERO,
+            Complex::ZERO,
+            Complex::ZERO,
+            Complex::new(1.0, 0.0),
+        ]);
+        assert_eq!(
+            Polynomial::new(vec![Complex::new(-1.0, 0.0)]),
+            cube.to_negacyclic(3).negacyclic_to_polynomial()
+        );
+    }
+
+    #[test]
+    fn test_solve_circulant() {
+        let mut rand = random::seeded(12);
+        let c = Polynomial::random(&mut rand);
+        let b = Matrix::new((0..16).map(|_| Complex::random(&mut rand)).collect(), 2, 8);
+
+        let tolerance = Tolerance::new().absolute(1e-3);
+        let x = Matrix::solve_circulant(&c, &b);
+        assert_approx_eq!(b, Matrix::mul(&c.to_circulant(8), &x), tolerance);
+        assert_approx_eq!(x, Matrix::solve(&c.to_circulant(8), &b), tolerance);
+
+        let x = Matrix::solve_negacyclic(&c, &b);
+        assert_approx_eq!(b, Matrix::mul(&c.to_negacyclic(8), &x), tolerance);
+
+        // 1 + x vanishes at -1, a 4-th root of unity.
+        let singular = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+        assert_eq!(
+            Err(Error::NotInvertible),
+            Matrix::try_solve_circulant(&singular, &Matrix::zeros(1, 4))
+        );
+    }
+}
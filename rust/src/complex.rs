@@ -1,9 +1,9 @@
 use std::{
     fmt::Display,
-    ops::{Add, Mul},
+    ops::{Add, Div, Mul, Sub},
 };
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -26,7 +26,21 @@ impl Complex {
         }
     }
 
-    pub fn random(rand: &mut ThreadRng) -> Complex {
+    /// Returns the modulus of the complex number.
+    pub fn norm(&self) -> f32 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the complex conjugate, negating the imaginary part.
+    pub fn conj(&self) -> Complex {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Generate a complex number with both parts uniformly distributed in `[-10, 10)`.
+    pub fn random<R: Rng + ?Sized>(rand: &mut R) -> Complex {
         let re = rand.gen_range(-10.0..10.0);
         let im = rand.gen_range(-10.0..10.0);
 
@@ -54,6 +68,26 @@ impl Add for Complex {
     }
 }
 
+impl<'b> Sub<&'b Complex> for &Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: &'b Complex) -> Self::Output {
+        Complex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    #[allow(clippy::op_ref)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
 #[cfg(not(feature = "simd"))]
 impl<'a, 'b> Mul<&'b Complex> for &'a Complex {
     type Output = Complex;
@@ -93,6 +127,31 @@ impl Mul for Complex {
     }
 }
 
+impl<'b> Div<&'b Complex> for &Complex {
+    type Output = Complex;
+
+    /// Divides by multiplying with the conjugate of `rhs`. Dividing by zero gives
+    /// non-finite parts, as with floats.
+    fn div(self, rhs: &'b Complex) -> Self::Output {
+        let norm_sqr = rhs.re * rhs.re + rhs.im * rhs.im;
+        let num = self * &rhs.conj();
+
+        Complex {
+            re: num.re / norm_sqr,
+            im: num.im / norm_sqr,
+        }
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    #[allow(clippy::op_ref)]
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
 impl Display for Complex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self == &Self::ZERO {
@@ -141,5 +200,12 @@ mod tests {
         let c2 = Complex::new(-1.0, -1.0);
 
         assert_eq!(Complex::new(0.0, 0.0), c1 * c2);
+
+        let c1 = Complex::new(-20.0, 15.0);
+        let c2 = Complex::new(3.0, 4.0);
+
+        assert_eq!(Complex::new(0.0, 5.0), c1 / c2);
+        assert_eq!(Complex::new(-23.0, 11.0), c1 - c2);
+        assert_eq!(5.0, c2.norm());
     }
 }
@@ -0,0 +1,98 @@
+//! A double precision complex number, used internally by the algorithms that need more
+//! precision than the `f32` parts of [`Complex`].
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::complex::Complex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct C64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl C64 {
+    pub const ZERO: C64 = C64 { re: 0.0, im: 0.0 };
+    pub const ONE: C64 = C64 { re: 1.0, im: 0.0 };
+
+    pub fn new(re: f64, im: f64) -> C64 {
+        C64 { re, im }
+    }
+
+    pub fn conj(self) -> C64 {
+        C64::new(self.re, -self.im)
+    }
+
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn scale(self, k: f64) -> C64 {
+        C64::new(self.re * k, self.im * k)
+    }
+
+    pub fn sqrt(self) -> C64 {
+        let r = self.norm();
+        let re = ((r + self.re) / 2.0).sqrt();
+        let im = ((r - self.re) / 2.0).sqrt();
+
+        C64::new(re, if self.im < 0.0 { -im } else { im })
+    }
+}
+
+impl From<Complex> for C64 {
+    fn from(c: Complex) -> Self {
+        C64::new(c.re as f64, c.im as f64)
+    }
+}
+
+impl From<C64> for Complex {
+    fn from(c: C64) -> Self {
+        Complex::new(c.re as f32, c.im as f32)
+    }
+}
+
+impl Add for C64 {
+    type Output = C64;
+
+    fn add(self, rhs: C64) -> C64 {
+        C64::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for C64 {
+    type Output = C64;
+
+    fn sub(self, rhs: C64) -> C64 {
+        C64::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for C64 {
+    type Output = C64;
+
+    fn mul(self, rhs: C64) -> C64 {
+        C64::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for C64 {
+    type Output = C64;
+
+    fn div(self, rhs: C64) -> C64 {
+        let norm_sqr = rhs.re * rhs.re + rhs.im * rhs.im;
+
+        (self * rhs.conj()).scale(1.0 / norm_sqr)
+    }
+}
+
+impl Neg for C64 {
+    type Output = C64;
+
+    fn neg(self) -> C64 {
+        C64::new(-self.re, -self.im)
+    }
+}
@@ -0,0 +1,157 @@
+//! Discrete Fourier transforms of complex sequences.
+//!
+//! The sums are accumulated in `f64` to limit the rounding errors of the `f32` [`Complex`].
+
+use std::f64::consts::TAU;
+
+use crate::{complex::Complex, complex64::C64};
+
+/// Returns the discrete Fourier transform of `values`: the `k`-th output is the polynomial
+/// whose coefficients are `values`, evaluated at `exp(2iπk / n)`.
+///
+/// Power-of-two lengths use a radix-2 fast Fourier transform, other lengths the `O(n^2)`
+/// definition.
+pub fn dft(values: &[Complex]) -> Vec<Complex> {
+    let mut values: Vec<C64> = values.iter().map(|&c| c.into()).collect();
+    transform(&mut values, 1.0);
+
+    values.into_iter().map(Complex::from).collect()
+}
+
+/// Returns the inverse discrete Fourier transform of `values`, such that
+/// `inverse_dft(&dft(values))` gives back `values`.
+pub fn inverse_dft(values: &[Complex]) -> Vec<Complex> {
+    let scale = 1.0 / values.len() as f64;
+    let mut values: Vec<C64> = values.iter().map(|&c| c.into()).collect();
+    transform(&mut values, -1.0);
+
+    values.into_iter().map(|c| c.scale(scale).into()).collect()
+}
+
+/// Replace `values` by their unscaled transform, evaluating the polynomial they're the
+/// coefficients of at the `exp(sign * 2iπk / n)`.
+pub(crate) fn transform(values: &mut [C64], sign: f64) {
+    let n = values.len();
+
+    if n.is_power_of_two() {
+        fast_transform(values, sign);
+        return;
+    }
+
+    let transformed: Vec<C64> = (0..n)
+        .map(|k| {
+            values.iter().enumerate().fold(C64::ZERO, |acc, (j, c)| {
+                let (sin, cos) = twiddle((j * k) % n, n, sign);
+
+                acc + *c * C64::new(cos, sin)
+            })
+        })
+        .collect();
+
+    values.copy_from_slice(&transformed);
+}
+
+/// Iterative radix-2 Cooley-Tukey transform, for power-of-two lengths.
+fn fast_transform(values: &mut [C64], sign: f64) {
+    let n = values.len();
+    if n == 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let twiddles: Vec<C64> = (0..n / 2)
+        .map(|step| {
+            let (sin, cos) = twiddle(step, n, sign);
+            C64::new(cos, sin)
+        })
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+
+        for block in values.chunks_mut(len) {
+            for j in 0..half {
+                let u = block[j];
+                let t = twiddles[j * stride] * block[j + half];
+
+                block[j] = u + t;
+                block[j + half] = u - t;
+            }
+        }
+
+        len *= 2;
+    }
+}
+
+/// Returns the sine and cosine of `sign * 2π * step / n`.
+///
+/// Quarter turns are exact, so that transforms of lengths 1, 2 and 4 don't leave rounding
+/// errors in place of zeros.
+fn twiddle(step: usize, n: usize, sign: f64) -> (f64, f64) {
+    if (4 * step).is_multiple_of(n) {
+        match 4 * step / n {
+            0 => (0.0, 1.0),
+            1 => (sign, 0.0),
+            2 => (0.0, -1.0),
+            _ => (-sign, 0.0),
+        }
+    } else {
+        (sign * TAU * step as f64 / n as f64).sin_cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dft_roundtrip() {
+        let values = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, -1.0),
+            Complex::new(0.0, 3.0),
+        ];
+
+        // 1 + 2x + 3x^2 at x = 1
+        assert_eq!(Complex::new(3.0, 2.0), dft(&values)[0]);
+
+        for (c1, c2) in values.iter().zip(inverse_dft(&dft(&values))) {
+            assert!((*c1 - c2).norm() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_fast_transform() {
+        let values: Vec<Complex> = (0..16)
+            .map(|i| Complex::new(i as f32, (i * i % 7) as f32))
+            .collect();
+
+        // The radix-2 path must agree with the definition, evaluating at the powers of
+        // exp(2iπ / 16).
+        let root = C64::new((TAU / 16.0).cos(), (TAU / 16.0).sin());
+        let mut point = C64::ONE;
+
+        for value in dft(&values) {
+            let expected = values
+                .iter()
+                .rev()
+                .fold(C64::ZERO, |acc, &c| acc * point + c.into());
+            assert!((expected - value.into()).norm() < 1e-3);
+
+            point = point * root;
+        }
+
+        for (c1, c2) in values.iter().zip(inverse_dft(&dft(&values))) {
+            assert!((*c1 - c2).norm() < 1e-5);
+        }
+    }
+}
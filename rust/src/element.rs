@@ -0,0 +1,337 @@
+use std::fmt::Debug;
+
+use crate::{
+    complex::Complex,
+    exact::GaussianRational,
+    finite_field::{Gf256, Gf2m},
+    matrix::Matrix,
+    modular::Zq,
+    ntt::{NttPolynomial, NttTables},
+    polynomial::Polynomial,
+};
+
+/// The elements a [`crate::matrix::Matrix`] can hold: anything with a zero, a one, an addition,
+/// a subtraction and a multiplication.
+///
+/// The multiplication doesn't need to be commutative, matrix products always multiply an
+/// element of the left operand by an element of the right operand, in that order.
+pub trait Element: Clone + Debug + PartialEq + Send + Sync {
+    /// The neutral element of the addition.
+    fn zero() -> Self;
+
+    /// The neutral element of the multiplication.
+    fn one() -> Self;
+
+    fn add(a: &Self, b: &Self) -> Self;
+
+    fn sub(a: &Self, b: &Self) -> Self;
+
+    fn mul(a: &Self, b: &Self) -> Self;
+
+    /// Returns the opposite of the element, such that `a + a.neg()` is zero.
+    fn neg(&self) -> Self {
+        Self::sub(&Self::zero(), self)
+    }
+
+    /// Add `a * b` to `acc` in place. Matrix products accumulate with it, so elements owning
+    /// buffers should reuse them instead of allocating.
+    fn mul_add(acc: &mut Self, a: &Self, b: &Self) {
+        *acc = Self::add(acc, &Self::mul(a, b));
+    }
+
+    /// Bring an element accumulated with [`Element::mul_add`] back to its canonical form.
+    fn normalized(self) -> Self {
+        self
+    }
+
+    /// Multiply two matrices of polynomials with coefficients of this type modulo
+    /// `x^ring - 1` faster than the classic kernel, or returns `None` to use the classic
+    /// kernel. [`Matrix::try_mul_in_ring`] calls it after having checked the sizes and the
+    /// ring.
+    fn mul_matrices_in_ring(
+        _a: &Matrix<Polynomial<Self>>,
+        _b: &Matrix<Polynomial<Self>>,
+        _ring: usize,
+    ) -> Option<Matrix<Polynomial<Self>>> {
+        None
+    }
+
+    /// Multiply two polynomials with coefficients of this type modulo `x^ring + 1` faster
+    /// than the schoolbook product, or returns `None` to use it.
+    /// [`Polynomial::try_mul_in_negacyclic_ring`] calls it after having checked the ring.
+    fn mul_polynomials_in_negacyclic_ring(
+        _a: &Polynomial<Self>,
+        _b: &Polynomial<Self>,
+        _ring: usize,
+    ) -> Option<Polynomial<Self>> {
+        None
+    }
+
+    /// Multiply two matrices of polynomials with coefficients of this type modulo
+    /// `x^ring + 1` faster than the classic kernel, or returns `None` to use it.
+    /// [`Matrix::try_mul_in_negacyclic_ring`] calls it after having checked the sizes and
+    /// the ring.
+    fn mul_matrices_in_negacyclic_ring(
+        _a: &Matrix<Polynomial<Self>>,
+        _b: &Matrix<Polynomial<Self>>,
+        _ring: usize,
+    ) -> Option<Matrix<Polynomial<Self>>> {
+        None
+    }
+}
+
+/// Elements that can be divided by, used as the coefficients of polynomial divisions and
+/// greatest common divisors, see [`Polynomial::euclidean_division`].
+///
+/// Non-zero elements don't all need an inverse, so that rings like `Z/qZ` with a composite
+/// `q` fit too: dividing by an element without one fails.
+pub trait Field: Element {
+    /// Returns the multiplicative inverse of the element, or `None` if it doesn't have one.
+    fn inv(&self) -> Option<Self>;
+
+    /// Returns `a / b`, or `None` if `b` isn't invertible.
+    fn div(a: &Self, b: &Self) -> Option<Self> {
+        b.inv().map(|inverse| Self::mul(a, &inverse))
+    }
+
+    /// Returns the magnitude of the element, which tells rounding errors apart from actual
+    /// values: an element whose magnitude is tiny next to the ones of the other coefficients
+    /// is taken for zero.
+    ///
+    /// Exact elements keep the default, 1 for any non-zero element, so that only zero is
+    /// negligible.
+    fn magnitude(&self) -> f32 {
+        if *self == Self::zero() {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Elements with a conjugation, used by [`crate::matrix::Matrix::conjugate_transpose`].
+pub trait Conjugate {
+    fn conj(&self) -> Self;
+}
+
+/// Implement [`Element`] and [`Field`] for a `Copy` type by forwarding to its operators on
+/// owned values, its `ZERO` and its inherent `inv`, with `one` as the neutral element of the
+/// multiplication. The items of the trailing block are added to the [`Element`] impl.
+macro_rules! impl_element_for_copy_ops {
+    ($t:ty $(where const $param:ident: $ty:ty)?, one: $one:expr $(, { $($extra:tt)* })?) => {
+        impl$(<const $param: $ty>)? Element for $t {
+            fn zero() -> Self {
+                <$t>::ZERO
+            }
+
+            fn one() -> Self {
+                $one
+            }
+
+            fn add(a: &Self, b: &Self) -> Self {
+                *a + *b
+            }
+
+            fn sub(a: &Self, b: &Self) -> Self {
+                *a - *b
+            }
+
+            fn mul(a: &Self, b: &Self) -> Self {
+                *a * *b
+            }
+
+            fn neg(&self) -> Self {
+                -*self
+            }
+
+            $($($extra)*)?
+        }
+
+        impl$(<const $param: $ty>)? Field for $t {
+            fn inv(&self) -> Option<Self> {
+                <$t>::inv(self)
+            }
+        }
+    };
+}
+
+impl Element for Complex {
+    fn zero() -> Self {
+        Complex::ZERO
+    }
+
+    fn one() -> Self {
+        Complex::new(1.0, 0.0)
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        a + b
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        a - b
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        a * b
+    }
+
+    fn neg(&self) -> Self {
+        Complex::neg(self)
+    }
+
+    fn mul_add(acc: &mut Self, a: &Self, b: &Self) {
+        *acc = *acc + a * b;
+    }
+
+    /// Large enough products are done by evaluation and interpolation, see
+    /// [`Matrix::mul_evaluation_in_ring`].
+    fn mul_matrices_in_ring(a: &Matrix, b: &Matrix, ring: usize) -> Option<Matrix> {
+        Matrix::evaluation_wins(a, b, ring).then(|| Matrix::mul_evaluation_in_ring(a, b, ring))
+    }
+}
+
+impl Field for Complex {
+    fn inv(&self) -> Option<Self> {
+        Self::div(&Complex::new(1.0, 0.0), self)
+    }
+
+    fn div(a: &Self, b: &Self) -> Option<Self> {
+        (*b != Complex::ZERO).then(|| a / b)
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.norm()
+    }
+}
+
+impl Element for GaussianRational {
+    fn zero() -> Self {
+        GaussianRational::zero()
+    }
+
+    fn one() -> Self {
+        GaussianRational::one()
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        a + b
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        a - b
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        a * b
+    }
+
+    fn neg(&self) -> Self {
+        -self
+    }
+}
+
+impl Field for GaussianRational {
+    fn inv(&self) -> Option<Self> {
+        GaussianRational::inv(self)
+    }
+}
+
+impl_element_for_copy_ops!(Zq<Q> where const Q: u32, one: Zq::new(1), {
+    /// Rings with [`NttTables`] multiply through the transforms.
+    fn mul_polynomials_in_negacyclic_ring(
+        a: &Polynomial<Self>,
+        b: &Polynomial<Self>,
+        ring: usize,
+    ) -> Option<Polynomial<Self>> {
+        NttTables::<Q>::cached(ring).map(|tables| tables.mul(a, b))
+    }
+
+    /// Rings with [`NttTables`] transform every polynomial once, see
+    /// [`NttTables::mul_matrices`].
+    fn mul_matrices_in_negacyclic_ring(
+        a: &Matrix<Polynomial<Self>>,
+        b: &Matrix<Polynomial<Self>>,
+        ring: usize,
+    ) -> Option<Matrix<Polynomial<Self>>> {
+        NttTables::<Q>::cached(ring).map(|tables| tables.mul_matrices(a, b))
+    }
+});
+
+impl<const Q: u32> Element for NttPolynomial<Q> {
+    fn zero() -> Self {
+        NttPolynomial::constant(Zq::ZERO)
+    }
+
+    fn one() -> Self {
+        NttPolynomial::constant(Zq::new(1))
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        NttPolynomial::zip_with(a, b, |x, y| x + y)
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        NttPolynomial::zip_with(a, b, |x, y| x - y)
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        NttPolynomial::pointwise_mul(a, b)
+    }
+}
+
+impl_element_for_copy_ops!(Gf2m<POLY> where const POLY: u32, one: Gf2m::ONE);
+
+impl_element_for_copy_ops!(Gf256, one: Gf256::ONE);
+
+impl<C: Element> Element for Polynomial<C> {
+    fn zero() -> Self {
+        Polynomial::zero()
+    }
+
+    fn one() -> Self {
+        Polynomial::one()
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        Polynomial::add(a, b)
+    }
+
+    fn sub(a: &Self, b: &Self) -> Self {
+        Polynomial::sub(a, b)
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        Polynomial::mul(a, b)
+    }
+
+    fn neg(&self) -> Self {
+        Polynomial::neg(self)
+    }
+
+    fn mul_add(acc: &mut Self, a: &Self, b: &Self) {
+        acc.mul_accumulate(a, b);
+    }
+
+    fn normalized(self) -> Self {
+        self.trim()
+    }
+}
+
+impl Conjugate for Complex {
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+}
+
+impl Conjugate for Polynomial {
+    fn conj(&self) -> Self {
+        Polynomial::conj(self)
+    }
+}
+
+impl Conjugate for GaussianRational {
+    fn conj(&self) -> Self {
+        GaussianRational::conj(self)
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+/// Errors returned by the fallible (`try_`-prefixed) operations of this crate.
+///
+/// Their panicking counterparts panic with the `Display` of these errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The operands of a matrix operation have incompatible sizes, given as `(width, height)`.
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The number of elements doesn't match the announced size.
+    LengthMismatch { expected: usize, found: usize },
+    /// An input that must contain at least one element was empty.
+    EmptyInput,
+    /// A string couldn't be parsed.
+    ParseError(String),
+    /// A division by a zero polynomial or scalar was attempted.
+    DivisionByZero,
+    /// The provided ring degree is invalid, for example 0.
+    InvalidRing(usize),
+    /// The element or matrix to invert has no inverse.
+    NotInvertible,
+    /// The operation requires a square matrix, given as `(width, height)`.
+    NotSquare((usize, usize)),
+    /// An iterative algorithm didn't converge within its iteration limit.
+    NoConvergence,
+    /// The matrix isn't circulant, or skew-circulant for the negacyclic conversions.
+    NotCirculant,
+    /// An index, given as `(row, column)`, is outside of a matrix of the given
+    /// `(width, height)`.
+    OutOfBounds {
+        index: (usize, usize),
+        size: (usize, usize),
+    },
+    /// A NaN or infinite float can't be converted to an exact number.
+    NotFinite,
+    /// The length `n` and dimension `k` of an error-correcting code are out of range.
+    InvalidCode { n: usize, k: usize },
+    /// A received word has more errors than its code can correct.
+    TooManyErrors,
+    /// A secret can't be split into `shares` shares, any `threshold` of which recover it.
+    InvalidThreshold { threshold: usize, shares: usize },
+    /// The width of a noise distribution is out of range.
+    InvalidNoise(u32),
+    /// The points at the indices `first` and `second` of an interpolation, or the shares of a
+    /// secret, have the same `x`.
+    DuplicatePoint { first: usize, second: usize },
+    /// The degree of a polynomial to build is invalid, for example 0.
+    InvalidDegree(usize),
+    /// The modulus of a field must be prime.
+    NotPrime(u32),
+    /// A ciphertext was encrypted for other parameters than those of the decryption key.
+    ParameterMismatch,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch between a {}x{} and a {}x{} matrix (width x height)",
+                left.0, left.1, right.0, right.1
+            ),
+            Error::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} elements, found {found}")
+            }
+            Error::EmptyInput => write!(f, "empty input"),
+            Error::ParseError(reason) => write!(f, "parse error: {reason}"),
+            Error::DivisionByZero => write!(f, "division by zero"),
+            Error::InvalidRing(ring) => write!(f, "invalid ring degree {ring}"),
+            Error::NotInvertible => write!(f, "not invertible"),
+            Error::NotSquare((width, height)) => {
+                write!(
+                    f,
+                    "expected a square matrix, found a {width}x{height} matrix"
+                )
+            }
+            Error::NoConvergence => write!(f, "the iterative algorithm didn't converge"),
+            Error::NotCirculant => write!(f, "not a circulant matrix"),
+            Error::OutOfBounds { index, size } => write!(
+                f,
+                "index ({}, {}) out of bounds for a {}x{} matrix (width x height)",
+                index.0, index.1, size.0, size.1
+            ),
+            Error::NotFinite => write!(f, "not a finite number"),
+            Error::InvalidCode { n, k } => write!(f, "invalid code parameters n = {n}, k = {k}"),
+            Error::TooManyErrors => write!(f, "too many errors to correct"),
+            Error::InvalidThreshold { threshold, shares } => {
+                write!(f, "invalid threshold {threshold} for {shares} shares")
+            }
+            Error::InvalidNoise(eta) => write!(f, "invalid noise width {eta}"),
+            Error::DuplicatePoint { first, second } => {
+                write!(f, "points {first} and {second} have the same x")
+            }
+            Error::InvalidDegree(degree) => write!(f, "invalid degree {degree}"),
+            Error::NotPrime(modulus) => write!(f, "the modulus {modulus} isn't prime"),
+            Error::ParameterMismatch => {
+                write!(f, "the ciphertext was encrypted for other parameters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
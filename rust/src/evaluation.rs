@@ -0,0 +1,251 @@
+//! Evaluation-based multiplication of polynomial matrices.
+//!
+//! Every entry of both operands is evaluated at the same roots of unity with a DFT, the
+//! matrices of values are multiplied point by point, and the entries of the product are
+//! interpolated back with an inverse DFT. For `n`x`n` matrices of degree `d` polynomials,
+//! this turns the `O(n^3 d^2)` of the classic product into `O(n^3 d + n^2 d log d)`.
+//!
+//! Transforms and products are done in `f64`, the results are only rounded to `f32` at the
+//! end.
+
+use crate::{complex64::C64, dft, error::Error, matrix::Matrix, polynomial::Polynomial};
+
+/// Relative magnitude below which the parts of interpolated coefficients are rounding noise, and are
+/// flushed to zero so that results get trimmed like the classic product's.
+const NOISE_THRESHOLD: f64 = 1e-12;
+
+/// How many times more expensive an operation of the evaluation-based product is than one of
+/// the classic product, to account for the transforms' bookkeeping.
+const EVALUATION_OVERHEAD: usize = 4;
+
+impl Matrix<Polynomial> {
+    /// Multiply two matrices by evaluating their entries at roots of unity, multiplying the
+    /// matrices of values point by point and interpolating the result. The width of `a` must
+    /// match the height of `b`.
+    ///
+    /// The result matches [`Matrix::mul`] up to rounding errors.
+    ///
+    /// Panics if the sizes don't match, see [`Matrix::try_mul_evaluation`].
+    pub fn mul_evaluation(a: &Matrix, b: &Matrix) -> Matrix {
+        Self::try_mul_evaluation(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices by evaluation and interpolation, returning an error if the
+    /// width of `a` doesn't match the height of `b`.
+    pub fn try_mul_evaluation(a: &Matrix, b: &Matrix) -> Result<Matrix, Error> {
+        Self::check_mul_size(a, b)?;
+
+        // A cyclic convolution at least as long as the products is a plain product, and
+        // power-of-two lengths use the fast transform.
+        let len = max_len(a) + max_len(b) - 1;
+
+        Ok(evaluation_product(a, b, len.next_power_of_two(), len))
+    }
+
+    /// Multiply two matrices by evaluation and interpolation while restricting the contained
+    /// polynomials to the provided ring degree. Reducing modulo `x^ring - 1` is a cyclic
+    /// convolution, so the entries are evaluated at the `ring`-th roots of unity directly.
+    ///
+    /// The result matches [`Matrix::mul_in_ring`] up to rounding errors.
+    ///
+    /// Panics if the width of `a` doesn't match the height of `b` or if `ring` is 0, see
+    /// [`Matrix::try_mul_evaluation_in_ring`].
+    pub fn mul_evaluation_in_ring(a: &Matrix, b: &Matrix, ring: usize) -> Matrix {
+        Self::try_mul_evaluation_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices by evaluation and interpolation while restricting the contained
+    /// polynomials to the provided ring degree, returning an error if the width of `a`
+    /// doesn't match the height of `b` or if `ring` is 0.
+    pub fn try_mul_evaluation_in_ring(
+        a: &Matrix,
+        b: &Matrix,
+        ring: usize,
+    ) -> Result<Matrix, Error> {
+        Self::check_mul_size(a, b)?;
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        Ok(evaluation_product(a, b, ring, ring))
+    }
+
+    /// Returns whether [`Matrix::mul_evaluation_in_ring`] is expected to be faster than the
+    /// classic kernel for these operands, from an estimation of the number of operations.
+    pub(crate) fn evaluation_wins(a: &Matrix, b: &Matrix, ring: usize) -> bool {
+        let (n, m, p) = (a.height, a.width, b.width);
+        let (la, lb) = (max_len(a).min(ring), max_len(b).min(ring));
+
+        let transform = if ring.is_power_of_two() {
+            ring * (ring.trailing_zeros() as usize).max(1)
+        } else {
+            ring * ring
+        };
+
+        let classic = n * m * p * la * lb;
+        let evaluation = (n * m + m * p + n * p) * transform + n * m * p * ring;
+
+        evaluation * EVALUATION_OVERHEAD < classic
+    }
+}
+
+/// Returns the number of coefficients of the longest entry of `m`.
+fn max_len(m: &Matrix) -> usize {
+    m.content
+        .iter()
+        .map(|p| p.coefficients.len())
+        .max()
+        .unwrap_or(1)
+}
+
+/// Multiply `a` and `b` in the ring of polynomials modulo `x^points - 1`, keeping the first
+/// `keep` coefficients of the results.
+fn evaluation_product(a: &Matrix, b: &Matrix, points: usize, keep: usize) -> Matrix {
+    let (n, m, p) = (a.height, a.width, b.width);
+    let a_values = evaluate(a, points);
+    let b_values = evaluate(b, points);
+
+    // One product of `n`x`m` by `m`x`p` matrices of values per point, all stored point-major.
+    let mut values = vec![C64::ZERO; points * n * p];
+
+    for k in 0..points {
+        let a_k = &a_values[k * n * m..(k + 1) * n * m];
+        let b_k = &b_values[k * m * p..(k + 1) * m * p];
+        let c_k = &mut values[k * n * p..(k + 1) * n * p];
+
+        for (a_row, c_row) in a_k.chunks(m).zip(c_k.chunks_mut(p)) {
+            for (x, b_row) in a_row.iter().zip(b_k.chunks(p)) {
+                for (c, y) in c_row.iter_mut().zip(b_row) {
+                    *c = *c + *x * *y;
+                }
+            }
+        }
+    }
+
+    Matrix {
+        width: p,
+        height: n,
+        content: interpolate(&values, points, n * p, keep),
+    }
+}
+
+/// Returns the values of every entry of `m` at the `points`-th roots of unity, point-major:
+/// the values at the `k`-th root are the row-major matrix starting at `k * width * height`.
+///
+/// Coefficients beyond `points` wrap around, which reduces the entries modulo
+/// `x^points - 1`.
+fn evaluate(m: &Matrix, points: usize) -> Vec<C64> {
+    let entries = m.content.len();
+    let mut values = vec![C64::ZERO; points * entries];
+    let mut buffer = vec![C64::ZERO; points];
+
+    for (e, polynomial) in m.content.iter().enumerate() {
+        buffer.fill(C64::ZERO);
+        for (i, c) in polynomial.coefficients.iter().enumerate() {
+            buffer[i % points] = buffer[i % points] + C64::from(*c);
+        }
+
+        dft::transform(&mut buffer, 1.0);
+
+        for (k, value) in buffer.iter().enumerate() {
+            values[e + k * entries] = *value;
+        }
+    }
+
+    values
+}
+
+/// Interpolate the `entries` polynomials whose point-major `values` at the `points`-th roots
+/// of unity are given, keeping their first `keep` coefficients.
+fn interpolate(values: &[C64], points: usize, entries: usize, keep: usize) -> Vec<Polynomial> {
+    let scale = 1.0 / points as f64;
+    let mut buffer = vec![C64::ZERO; points];
+
+    (0..entries)
+        .map(|e| {
+            for (k, value) in buffer.iter_mut().enumerate() {
+                *value = values[e + k * entries];
+            }
+
+            dft::transform(&mut buffer, -1.0);
+
+            let threshold = buffer.iter().map(|c| c.norm()).fold(0.0, f64::max) * NOISE_THRESHOLD;
+            let flush = |x: f64| if x.abs() <= threshold { 0.0 } else { x * scale };
+
+            let coefficients = buffer[..keep]
+                .iter()
+                .map(|c| C64::new(flush(c.re), flush(c.im)).into())
+                .collect();
+
+            Polynomial::new(coefficients).trim()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        approx::Tolerance,
+        assert_approx_eq,
+        random::{self, CoefficientDistribution, RandomMatrixConfig, RandomPolynomialConfig},
+    };
+
+    #[test]
+    fn test_mul_evaluation() {
+        let mut rand = random::seeded(7);
+        let a = Matrix::random(5, 3, &mut rand);
+        let b = Matrix::random(4, 5, &mut rand);
+
+        let tolerance = Tolerance::new().absolute(1e-3);
+        assert_approx_eq!(
+            Matrix::mul(&a, &b),
+            Matrix::mul_evaluation(&a, &b),
+            tolerance
+        );
+        for ring in [1, 3, 4, 7] {
+            assert_approx_eq!(
+                Matrix::mul_in_ring(&a, &b, ring),
+                Matrix::mul_evaluation_in_ring(&a, &b, ring),
+                tolerance
+            );
+        }
+
+        assert!(Matrix::try_mul_evaluation(&b, &b).is_err());
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Matrix::try_mul_evaluation_in_ring(&a, &b, 0)
+        );
+    }
+
+    #[test]
+    fn test_mul_evaluation_exact() {
+        // Products of integer polynomials come back exactly, trimmed like the classic ones.
+        let mut rand = random::seeded(8);
+        let config = RandomMatrixConfig::new(6, 6).entries(
+            RandomPolynomialConfig::new()
+                .degrees(0..=5)
+                .distribution(CoefficientDistribution::Integer { bound: 4 }),
+        );
+        let a = config.generate(&mut rand);
+        let b = config.generate(&mut rand);
+
+        assert_eq!(Matrix::mul(&a, &b), Matrix::mul_evaluation(&a, &b));
+        assert_eq!(
+            Matrix::mul_in_ring(&a, &b, 4),
+            Matrix::mul_evaluation_in_ring(&a, &b, 4)
+        );
+    }
+
+    #[test]
+    fn test_evaluation_wins() {
+        let mut rand = random::seeded(9);
+        let small = Matrix::random(2, 2, &mut rand);
+        let config =
+            RandomMatrixConfig::new(32, 32).entries(RandomPolynomialConfig::new().degrees(31..=31));
+        let large = config.generate(&mut rand);
+
+        assert!(!Matrix::evaluation_wins(&small, &small, 4));
+        assert!(Matrix::evaluation_wins(&large, &large, 32));
+    }
+}
@@ -0,0 +1,484 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::error::Error;
+
+/// The base of the limbs of the magnitudes.
+const BASE: u64 = 1 << 32;
+
+/// An arbitrary-precision signed integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    /// The absolute value, in base 2^32 from the least significant limb, without leading zero
+    /// limbs: zero has no limbs.
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn zero() -> BigInt {
+        BigInt {
+            negative: false,
+            magnitude: vec![],
+        }
+    }
+
+    pub fn one() -> BigInt {
+        BigInt::from(1)
+    }
+
+    /// Create an integer from its sign and magnitude, bringing it to its canonical form.
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    /// Returns `self` raised to the power `exponent`, by squaring.
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::one();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the quotient and the remainder of the division of `self` by `divisor`,
+    /// rounding the quotient toward zero like the primitive integers do: the remainder has
+    /// the sign of `self`.
+    ///
+    /// Panics if `divisor` is zero, see [`BigInt::try_div_rem`].
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        self.try_div_rem(divisor)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the quotient and the remainder of the division of `self` by `divisor`, or an
+    /// error if `divisor` is zero.
+    pub fn try_div_rem(&self, divisor: &BigInt) -> Result<(BigInt, BigInt), Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let (quotient, remainder) = div_rem_magnitudes(&self.magnitude, &divisor.magnitude);
+
+        Ok((
+            BigInt::from_parts(self.negative != divisor.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        ))
+    }
+
+    /// Returns the non-negative greatest common divisor of `a` and `b`, zero if both are
+    /// zero.
+    pub fn gcd(a: &BigInt, b: &BigInt) -> BigInt {
+        let (mut a, mut b) = (a.abs(), b.abs());
+
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b);
+            (a, b) = (b, remainder);
+        }
+
+        a
+    }
+
+    /// Returns the closest `f64` to the integer, or an infinity if it's too large.
+    pub fn to_f64(&self) -> f64 {
+        let value = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &limb| acc * BASE as f64 + limb as f64);
+
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                #[allow(unused_comparisons)]
+                fn from(value: $t) -> Self {
+                    let mut magnitude = vec![];
+                    let mut rest = (value as i128).unsigned_abs();
+                    while rest > 0 {
+                        magnitude.push(rest as u32);
+                        rest >>= 32;
+                    }
+
+                    BigInt::from_parts(value < 0, magnitude)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(i32, i64, u32, u64, usize);
+
+/// Compare two magnitudes without leading zero limbs.
+fn cmp_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (i, &x) in long.iter().enumerate() {
+        let sum = x as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+
+    result
+}
+
+/// Returns `a - b`, with `a` at least `b`.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+
+    for (i, &x) in a.iter().enumerate() {
+        let difference = x as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        result.push(difference as u32);
+        borrow = (difference < 0) as i64;
+    }
+
+    result
+}
+
+fn mul_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+
+    result
+}
+
+/// Returns the quotient and the remainder of the division of `u` by the non-zero `v`, with
+/// Knuth's algorithm D (The Art of Computer Programming, vol. 2, 4.3.1).
+fn div_rem_magnitudes(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitudes(u, v) == Ordering::Less {
+        return (vec![], u.to_vec());
+    }
+
+    if let [divisor] = v {
+        let divisor = *divisor as u64;
+        let mut quotient = vec![0; u.len()];
+        let mut remainder = 0;
+
+        for (q, &limb) in quotient.iter_mut().zip(u).rev() {
+            let current = (remainder << 32) | limb as u64;
+            *q = (current / divisor) as u32;
+            remainder = current % divisor;
+        }
+
+        return (quotient, vec![remainder as u32]);
+    }
+
+    // Normalize so that the top limb of the divisor has its highest bit set, which keeps the
+    // estimates of the quotient limbs at most 2 above the actual ones.
+    let shift = v[v.len() - 1].leading_zeros();
+    let v = shift_left(v, shift);
+    let mut u = shift_left(u, shift);
+    u.push(0);
+
+    let n = v.len();
+    let m = u.len() - n;
+    let mut quotient = vec![0; m];
+
+    for j in (0..m).rev() {
+        let top = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut q = top / v[n - 1] as u64;
+        let mut r = top % v[n - 1] as u64;
+
+        while q >= BASE || q * v[n - 2] as u64 > ((r << 32) | u[j + n - 2] as u64) {
+            q -= 1;
+            r += v[n - 1] as u64;
+            if r >= BASE {
+                break;
+            }
+        }
+
+        // Subtract `q * v` from the current window of `u`.
+        let mut borrow = 0;
+        let mut carry = 0;
+        for i in 0..n {
+            let product = q * v[i] as u64 + carry;
+            carry = product >> 32;
+            let difference = u[i + j] as i64 - borrow - (product as u32) as i64;
+            u[i + j] = difference as u32;
+            borrow = (difference < 0) as i64;
+        }
+        let difference = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = difference as u32;
+
+        // The estimate was one too large: add `v` back.
+        if difference < 0 {
+            q -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = q as u32;
+    }
+
+    u.truncate(n);
+
+    (quotient, shift_right(&u, shift))
+}
+
+/// Shift a magnitude left by less than 32 bits, without dropping any bit.
+fn shift_left(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &limb in a {
+        result.push((limb << shift) | carry);
+        carry = limb >> (32 - shift);
+    }
+    if carry != 0 {
+        result.push(carry);
+    }
+
+    result
+}
+
+/// Shift a magnitude right by less than 32 bits.
+fn shift_right(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+
+    let mut result = vec![0; a.len()];
+    for i in 0..a.len() {
+        let high = a.get(i + 1).map_or(0, |&limb| limb << (32 - shift));
+        result[i] = (a[i] >> shift) | high;
+    }
+
+    result
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl<'b> Add<&'b BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &'b BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitudes(&self.magnitude, &rhs.magnitude),
+            );
+        }
+
+        // Opposite signs: subtract the smallest magnitude from the largest one.
+        match cmp_magnitudes(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                rhs.negative,
+                sub_magnitudes(&rhs.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl<'b> Sub<&'b BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &'b BigInt) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl<'b> Mul<&'b BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &'b BigInt) -> Self::Output {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitudes(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl<'b> Div<&'b BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Panics if `rhs` is zero, see [`BigInt::try_div_rem`].
+    fn div(self, rhs: &'b BigInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<'b> Rem<&'b BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Panics if `rhs` is zero, see [`BigInt::try_div_rem`].
+    fn rem(self, rhs: &'b BigInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_owned_ops!(BigInt: Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // Peel off groups of 9 decimal digits, from the least significant one.
+        let chunk = BigInt::from(1_000_000_000);
+        let mut rest = self.abs();
+        let mut groups = vec![];
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem(&chunk);
+            groups.push(remainder.magnitude.first().copied().unwrap_or(0));
+            rest = quotient;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", groups[groups.len() - 1])?;
+        for group in groups.iter().rev().skip(1) {
+            write!(f, "{group:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = BigInt::from(u64::MAX);
+        let b = BigInt::from(-12345678901234_i64);
+
+        assert_eq!("18446744073709551615", a.to_string());
+        assert_eq!(
+            "340282366920938463426481119284349108225",
+            (&a * &a).to_string()
+        );
+        assert_eq!(a, &(&a + &b) - &b);
+        assert_eq!(BigInt::zero(), &a - &a);
+        assert_eq!(BigInt::from(-1), &BigInt::from(3) - &BigInt::from(4));
+        assert!(b < BigInt::zero() && BigInt::zero() < a);
+        assert_eq!(
+            BigInt::from(2).pow(100),
+            &BigInt::from(1u64 << 50) * &BigInt::from(1u64 << 50)
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // Multi-limb divisors go through the full long division.
+        let a = &BigInt::from(2).pow(190) + &BigInt::from(12345);
+        let b = &BigInt::from(2).pow(96) - &BigInt::one();
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(a, &(&q * &b) + &r);
+        assert!(r >= BigInt::zero() && r < b);
+
+        // The estimate of the quotient limb is one too large here, and gets corrected by
+        // adding the divisor back.
+        let a = &BigInt::from(2).pow(127) + &BigInt::from(0xfffe_u64 << 32);
+        let b = &BigInt::from(2).pow(95) + &BigInt::from(0xffff);
+        assert_eq!("39614081257132168792477073407", a.div_rem(&b).1.to_string());
+        assert_eq!(BigInt::from(u32::MAX), &a / &b);
+
+        for (x, y) in [(7, 2), (-7, 2), (7, -2), (-7, -2)] {
+            let (q, r) = BigInt::from(x).div_rem(&BigInt::from(y));
+            assert_eq!((BigInt::from(x / y), BigInt::from(x % y)), (q, r));
+        }
+
+        assert_eq!(
+            Err(Error::DivisionByZero),
+            BigInt::one().try_div_rem(&BigInt::zero())
+        );
+        assert_eq!(
+            BigInt::from(6),
+            BigInt::gcd(&BigInt::from(-42), &BigInt::from(120))
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::Rational;
+use crate::{complex::Complex, error::Error, matrix::Matrix};
+
+/// An exact complex number, with rational real and imaginary parts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GaussianRational {
+    pub re: Rational,
+    pub im: Rational,
+}
+
+impl GaussianRational {
+    pub fn new(re: Rational, im: Rational) -> GaussianRational {
+        GaussianRational { re, im }
+    }
+
+    pub fn zero() -> GaussianRational {
+        GaussianRational::from(Rational::zero())
+    }
+
+    pub fn one() -> GaussianRational {
+        GaussianRational::from(Rational::one())
+    }
+
+    /// The imaginary unit.
+    pub fn i() -> GaussianRational {
+        GaussianRational::new(Rational::zero(), Rational::one())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    /// Returns the complex conjugate, negating the imaginary part.
+    pub fn conj(&self) -> GaussianRational {
+        GaussianRational::new(self.re.clone(), -&self.im)
+    }
+
+    /// Returns the squared modulus, which unlike the modulus is always rational.
+    pub fn norm_squared(&self) -> Rational {
+        &(&self.re * &self.re) + &(&self.im * &self.im)
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<GaussianRational> {
+        let inverse = self.norm_squared().inv()?;
+
+        Some(GaussianRational::new(
+            &self.re * &inverse,
+            &-&self.im * &inverse,
+        ))
+    }
+
+    /// Returns the closest [`Complex`] to the number, rounding both parts.
+    pub fn to_complex(&self) -> Complex {
+        Complex::new(self.re.to_f64() as f32, self.im.to_f64() as f32)
+    }
+}
+
+impl From<Rational> for GaussianRational {
+    fn from(value: Rational) -> Self {
+        GaussianRational::new(value, Rational::zero())
+    }
+}
+
+impl From<i64> for GaussianRational {
+    fn from(value: i64) -> Self {
+        GaussianRational::from(Rational::from(value))
+    }
+}
+
+impl TryFrom<Complex> for GaussianRational {
+    type Error = Error;
+
+    /// Converts the complex number exactly, or fails if one of its parts is NaN or infinite.
+    fn try_from(value: Complex) -> Result<Self, Self::Error> {
+        Ok(GaussianRational::new(
+            Rational::try_from(value.re)?,
+            Rational::try_from(value.im)?,
+        ))
+    }
+}
+
+impl Neg for &GaussianRational {
+    type Output = GaussianRational;
+
+    fn neg(self) -> GaussianRational {
+        GaussianRational::new(-&self.re, -&self.im)
+    }
+}
+
+impl<'b> Add<&'b GaussianRational> for &GaussianRational {
+    type Output = GaussianRational;
+
+    fn add(self, rhs: &'b GaussianRational) -> Self::Output {
+        GaussianRational::new(&self.re + &rhs.re, &self.im + &rhs.im)
+    }
+}
+
+impl<'b> Sub<&'b GaussianRational> for &GaussianRational {
+    type Output = GaussianRational;
+
+    fn sub(self, rhs: &'b GaussianRational) -> Self::Output {
+        GaussianRational::new(&self.re - &rhs.re, &self.im - &rhs.im)
+    }
+}
+
+impl<'b> Mul<&'b GaussianRational> for &GaussianRational {
+    type Output = GaussianRational;
+
+    fn mul(self, rhs: &'b GaussianRational) -> Self::Output {
+        GaussianRational::new(
+            &(&self.re * &rhs.re) - &(&self.im * &rhs.im),
+            &(&self.re * &rhs.im) + &(&self.im * &rhs.re),
+        )
+    }
+}
+
+impl<'b> Div<&'b GaussianRational> for &GaussianRational {
+    type Output = GaussianRational;
+
+    /// Divides by multiplying with the conjugate of `rhs`.
+    ///
+    /// Panics if `rhs` is zero, see [`GaussianRational::inv`].
+    fn div(self, rhs: &'b GaussianRational) -> Self::Output {
+        let norm_squared = rhs.norm_squared();
+        if norm_squared.is_zero() {
+            panic!("{}", Error::DivisionByZero);
+        }
+        let num = self * &rhs.conj();
+
+        GaussianRational::new(&num.re / &norm_squared, &num.im / &norm_squared)
+    }
+}
+
+forward_owned_ops!(GaussianRational: Add add, Sub sub, Mul mul, Div div);
+
+impl Display for GaussianRational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else if self.im.is_zero() {
+            write!(f, "{}", self.re)
+        } else if self.re.is_zero() {
+            write!(f, "{}i", self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl Matrix<GaussianRational> {
+    /// Returns the exact determinant of a square matrix.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_determinant`].
+    pub fn determinant(&self) -> GaussianRational {
+        self.try_determinant().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the exact determinant of the matrix with a Gaussian elimination, or an error
+    /// if it isn't square.
+    ///
+    /// Any non-zero pivot will do since there's no rounding, so the first one in the column is
+    /// taken.
+    pub fn try_determinant(&self) -> Result<GaussianRational, Error> {
+        if self.width != self.height {
+            return Err(Error::NotSquare((self.width, self.height)));
+        }
+
+        let n = self.width;
+        let mut m = self.content.clone();
+        let mut determinant = GaussianRational::one();
+
+        for k in 0..n {
+            let Some(pivot_row) = (k..n).find(|&i| !m[k + i * n].is_zero()) else {
+                return Ok(GaussianRational::zero());
+            };
+
+            if pivot_row != k {
+                for j in k..n {
+                    m.swap(j + k * n, j + pivot_row * n);
+                }
+                determinant = -determinant;
+            }
+
+            let pivot_inverse = m[k + k * n].inv().ok_or(Error::NotInvertible)?;
+            determinant = &determinant * &m[k + k * n];
+
+            for i in k + 1..n {
+                let factor = &m[k + i * n] * &pivot_inverse;
+                for j in k + 1..n {
+                    m[j + i * n] = &m[j + i * n] - &(&factor * &m[j + k * n]);
+                }
+            }
+        }
+
+        Ok(determinant)
+    }
+
+    /// Returns the closest complex matrix, rounding every element.
+    pub fn to_complex(&self) -> Matrix<Complex> {
+        self.map(GaussianRational::to_complex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exact::BigInt, polynomial::Polynomial};
+
+    fn gaussian(re: (i64, i64), im: (i64, i64)) -> GaussianRational {
+        GaussianRational::new(
+            Rational::new(BigInt::from(re.0), BigInt::from(re.1)),
+            Rational::new(BigInt::from(im.0), BigInt::from(im.1)),
+        )
+    }
+
+    fn third() -> GaussianRational {
+        gaussian((1, 3), (0, 1))
+    }
+
+    /// Returns the monic polynomial whose roots are `roots`.
+    fn from_roots(roots: &[GaussianRational]) -> Polynomial<GaussianRational> {
+        roots.iter().fold(Polynomial::one(), |p, root| {
+            Polynomial::mul(&p, &Polynomial::new(vec![-root, GaussianRational::one()]))
+        })
+    }
+
+    #[test]
+    fn test_gaussian_arithmetic() {
+        let a = gaussian((1, 2), (-1, 3));
+        let b = gaussian((2, 1), (3, 4));
+
+        assert_eq!(gaussian((5, 2), (5, 12)), &a + &b);
+        assert_eq!(gaussian((5, 4), (-7, 24)), &a * &b);
+        assert_eq!(a, &(&a * &b) / &b);
+        assert_eq!(
+            GaussianRational::from(-1),
+            GaussianRational::i() * GaussianRational::i()
+        );
+        assert_eq!(
+            GaussianRational::from(Rational::new(BigInt::from(13), BigInt::from(36))),
+            &a * &a.conj()
+        );
+        assert_eq!(None, GaussianRational::zero().inv());
+        assert_eq!("1/2 + -1/3i", a.to_string());
+
+        let c = Complex::new(0.1, -2.5);
+        assert_eq!(c, GaussianRational::try_from(c).unwrap().to_complex());
+        assert_eq!(
+            Err(Error::NotFinite),
+            GaussianRational::try_from(Complex::new(f32::NAN, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_exact_polynomials() {
+        let p = from_roots(&[third(), gaussian((0, 1), (-1, 2)), gaussian((2, 1), (0, 1))]);
+        let q = from_roots(&[third(), gaussian((-5, 1), (1, 7))]);
+
+        assert_eq!(from_roots(&[third()]), Polynomial::gcd(&p, &q));
+
+        let (g, s, t) = Polynomial::extended_gcd(&p, &q);
+        assert_eq!(
+            g,
+            Polynomial::add(&Polynomial::mul(&s, &p), &Polynomial::mul(&t, &q)).trim()
+        );
+
+        let (quotient, remainder) = Polynomial::euclidean_division(&p, &q);
+        assert_eq!(
+            p,
+            Polynomial::add(&Polynomial::mul(&quotient, &q), &remainder).trim()
+        );
+        assert_eq!(GaussianRational::zero(), p.eval(third()));
+
+        // Coprime polynomials whose float GCD is fooled by a common root off by 1e-6.
+        let close = from_roots(&[gaussian((1_000_001, 1_000_000), (0, 1))]);
+        let one = from_roots(&[GaussianRational::one()]);
+        assert_eq!(Polynomial::one(), Polynomial::gcd(&close, &one));
+        let (close, one) = (
+            close.map(GaussianRational::to_complex),
+            one.map(GaussianRational::to_complex),
+        );
+        assert_eq!(1, Polynomial::gcd(&close, &one).degree);
+    }
+
+    #[test]
+    fn test_exact_determinants() {
+        let m = Matrix::new(
+            vec![
+                gaussian((1, 2), (0, 1)),
+                gaussian((1, 3), (1, 1)),
+                gaussian((0, 1), (0, 1)),
+                gaussian((0, 1), (0, 1)),
+                gaussian((0, 1), (0, 1)),
+                gaussian((2, 1), (0, 1)),
+                gaussian((1, 1), (0, 1)),
+                gaussian((3, 1), (-1, 2)),
+                gaussian((1, 5), (0, 1)),
+            ],
+            3,
+            3,
+        );
+        // Expanding along the second row: -2 * (1/2 (3 - i/2) - (1/3 + i))
+        assert_eq!(gaussian((-7, 3), (5, 2)), m.determinant());
+        assert_eq!(
+            GaussianRational::zero(),
+            Matrix::new(vec![third(), third(), third(), third()], 2, 2).determinant()
+        );
+        assert_eq!(
+            Err(Error::NotSquare((2, 1))),
+            Matrix::new(vec![third(), third()], 2, 1).try_determinant()
+        );
+
+        // Matrices of exact polynomials have exact determinants too: diag(x - 1/3, x + 1/3)
+        // has the determinant x^2 - 1/9.
+        let x = |c: GaussianRational| Polynomial::new(vec![c, GaussianRational::one()]);
+        let p = Matrix::new(
+            vec![
+                x(-third()),
+                Polynomial::zero(),
+                Polynomial::one(),
+                x(third()),
+            ],
+            2,
+            2,
+        );
+        assert_eq!(
+            Polynomial::new(vec![
+                gaussian((-1, 9), (0, 1)),
+                GaussianRational::zero(),
+                GaussianRational::one()
+            ]),
+            p.determinant()
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Exact scalars: arbitrary-precision integers, rationals, and complex numbers with rational
+//! parts.
+//!
+//! [`GaussianRational`] is a [`crate::element::Field`], so polynomials and matrices over it
+//! divide, compute GCDs and determinants without any rounding, at the cost of coefficients
+//! growing as large as they need to. [`GaussianRational::try_from`] converts a [`Complex`]
+//! exactly, since every finite float is a dyadic rational.
+//!
+//! [`Complex`]: crate::complex::Complex
+
+/// Implement the binary operators on owned values, and the negation, by forwarding to the
+/// implementations on references.
+macro_rules! forward_owned_ops {
+    ($t:ty: $($trait:ident $method:ident),*) => {
+        $(
+            impl std::ops::$trait for $t {
+                type Output = $t;
+
+                #[allow(clippy::op_ref)]
+                fn $method(self, rhs: Self) -> Self::Output {
+                    std::ops::$trait::$method(&self, &rhs)
+                }
+            }
+        )*
+
+        impl std::ops::Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> Self::Output {
+                -&self
+            }
+        }
+    };
+}
+
+mod big_int;
+mod gaussian;
+mod rational;
+
+pub use big_int::BigInt;
+pub use gaussian::GaussianRational;
+pub use rational::Rational;
@@ -0,0 +1,256 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::BigInt;
+use crate::error::Error;
+
+/// An exact rational number, kept in lowest terms with a positive denominator so that equal
+/// numbers have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    pub fn zero() -> Rational {
+        Rational::from(BigInt::zero())
+    }
+
+    pub fn one() -> Rational {
+        Rational::from(BigInt::one())
+    }
+
+    /// Returns the fraction `numerator / denominator` in lowest terms.
+    ///
+    /// Panics if `denominator` is zero, see [`Rational::try_new`].
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Rational {
+        Self::try_new(numerator, denominator).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the fraction `numerator / denominator` in lowest terms, or an error if
+    /// `denominator` is zero.
+    pub fn try_new(numerator: BigInt, denominator: BigInt) -> Result<Rational, Error> {
+        if denominator.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let gcd = BigInt::gcd(&numerator, &denominator);
+        let (numerator, denominator) = if denominator.is_negative() {
+            (&-&numerator / &gcd, &-&denominator / &gcd)
+        } else {
+            (&numerator / &gcd, &denominator / &gcd)
+        };
+
+        Ok(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    /// The denominator, always positive.
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<Rational> {
+        Self::try_new(self.denominator.clone(), self.numerator.clone()).ok()
+    }
+
+    /// Returns the closest `f64` to the number, as long as the numerator and the denominator
+    /// both fit in one.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Rational {
+            numerator: value,
+            denominator: BigInt::one(),
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from(BigInt::from(value))
+    }
+}
+
+impl TryFrom<f32> for Rational {
+    type Error = Error;
+
+    /// Converts the float exactly, as the dyadic rational it represents, or fails if it's NaN
+    /// or infinite.
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(Error::NotFinite);
+        }
+
+        // A finite float is `mantissa * 2^exponent`, subnormals having the smallest exponent.
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 23) & 0xff) as i32;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            ((bits & 0x7f_ffff) as i64, -149)
+        } else {
+            (
+                ((bits & 0x7f_ffff) | 0x80_0000) as i64,
+                biased_exponent - 150,
+            )
+        };
+        let mantissa = if value.is_sign_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+
+        let power = BigInt::from(2).pow(exponent.unsigned_abs());
+        if exponent >= 0 {
+            Ok(Rational::from(&BigInt::from(mantissa) * &power))
+        } else {
+            Rational::try_new(BigInt::from(mantissa), power)
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The denominators are positive, so cross-multiplying keeps the order.
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl<'b> Add<&'b Rational> for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &'b Rational) -> Self::Output {
+        Rational::new(
+            &(&self.numerator * &rhs.denominator) + &(&rhs.numerator * &self.denominator),
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<'b> Sub<&'b Rational> for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &'b Rational) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl<'b> Mul<&'b Rational> for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &'b Rational) -> Self::Output {
+        Rational::new(
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<'b> Div<&'b Rational> for &Rational {
+    type Output = Rational;
+
+    /// Panics if `rhs` is zero, see [`Rational::inv`].
+    fn div(self, rhs: &'b Rational) -> Self::Output {
+        Rational::new(
+            &self.numerator * &rhs.denominator,
+            &self.denominator * &rhs.numerator,
+        )
+    }
+}
+
+forward_owned_ops!(Rational: Add add, Sub sub, Mul mul, Div div);
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == BigInt::one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        assert_eq!(rational(-1, 2), rational(3, -6));
+        assert_eq!("-1/2", rational(3, -6).to_string());
+        assert_eq!(rational(5, 6), rational(1, 2) + rational(1, 3));
+        assert_eq!(rational(1, 6), rational(1, 2) - rational(1, 3));
+        assert_eq!(rational(3, 2), rational(3, 4) / rational(1, 2));
+        assert_eq!(
+            Rational::one(),
+            rational(7, 3) * rational(7, 3).inv().unwrap()
+        );
+        assert!(rational(-1, 2) < rational(1, 3));
+
+        assert_eq!(None, Rational::zero().inv());
+        assert_eq!(
+            Err(Error::DivisionByZero),
+            Rational::try_new(BigInt::one(), BigInt::zero())
+        );
+    }
+
+    #[test]
+    fn test_from_float() {
+        assert_eq!(Ok(rational(-3, 8)), Rational::try_from(-0.375));
+        assert_eq!(Ok(Rational::zero()), Rational::try_from(-0.0));
+        assert_eq!(
+            Ok(Rational::from(BigInt::from(2).pow(100))),
+            Rational::try_from(2f32.powi(100))
+        );
+
+        // 0.1 isn't a dyadic rational, the float is the closest one.
+        let tenth = Rational::try_from(0.1).unwrap();
+        assert_eq!(&BigInt::from(2).pow(27), tenth.denominator());
+        assert_eq!(0.1f32 as f64, tenth.to_f64());
+
+        let smallest = Rational::try_from(f32::from_bits(1)).unwrap();
+        assert_eq!(&BigInt::from(2).pow(149), smallest.denominator());
+
+        assert_eq!(Err(Error::NotFinite), Rational::try_from(f32::NAN));
+        assert_eq!(Err(Error::NotFinite), Rational::try_from(f32::INFINITY));
+    }
+}
@@ -0,0 +1,201 @@
+//! Numeric linear algebra on complex matrices.
+//!
+//! Unlike the polynomial matrix functions, these borrow their matrix arguments, which stay
+//! owned by the caller.
+
+use super::into_raw_or_null;
+use crate::{complex::Complex, linalg::ComplexMatrix, matrix::Matrix};
+
+#[no_mangle]
+/// Create a complex matrix from `width * height` row-major values, which are copied.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_from_values(
+    values: *const Complex,
+    width: usize,
+    height: usize,
+) -> *mut ComplexMatrix {
+    unsafe {
+        into_raw_or_null(Matrix::try_new(
+            std::slice::from_raw_parts(values, width * height).to_vec(),
+            width,
+            height,
+        ))
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we drop an arbitrary address from the other side.
+pub unsafe extern "C" fn complex_matrix_free(matrix: *mut ComplexMatrix) {
+    unsafe {
+        drop(Box::from_raw(matrix));
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_width(matrix: *const ComplexMatrix) -> usize {
+    unsafe { (*matrix).width }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_height(matrix: *const ComplexMatrix) -> usize {
+    unsafe { (*matrix).height }
+}
+
+#[no_mangle]
+/// Write the value at (`row`, `column`) to `value`, returning false if the index is out of
+/// bounds.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_get(
+    matrix: *const ComplexMatrix,
+    row: usize,
+    column: usize,
+    value: *mut Complex,
+) -> bool {
+    unsafe {
+        match (*matrix).get(row, column) {
+            Some(&entry) => {
+                *value = entry;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_mul(
+    a: *const ComplexMatrix,
+    b: *const ComplexMatrix,
+) -> *mut ComplexMatrix {
+    unsafe { into_raw_or_null(Matrix::try_mul(&*a, &*b)) }
+}
+
+#[no_mangle]
+/// Solve `AX = B`, returning null if `A` isn't square, is singular, or if the sizes of `A`
+/// and `B` don't match.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_solve(
+    a: *const ComplexMatrix,
+    b: *const ComplexMatrix,
+) -> *mut ComplexMatrix {
+    unsafe { into_raw_or_null(Matrix::try_solve(&*a, &*b)) }
+}
+
+#[no_mangle]
+/// Write the LU decomposition with partial pivoting `PA = LU` of `matrix` to `l`, `u` and
+/// `permutation`, returning false if it isn't square. The `i`-th row of `PA` is the
+/// `permutation[i]`-th row of `A`, and `l` and `u` are to be freed with
+/// `complex_matrix_free`.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side, and `permutation` must
+/// have room for `width` values.
+pub unsafe extern "C" fn complex_matrix_lu(
+    matrix: *const ComplexMatrix,
+    l: *mut *mut ComplexMatrix,
+    u: *mut *mut ComplexMatrix,
+    permutation: *mut usize,
+) -> bool {
+    unsafe {
+        match (*matrix).try_lu() {
+            Ok(lu) => {
+                *l = Box::into_raw(Box::new(lu.l()));
+                *u = Box::into_raw(Box::new(lu.u()));
+                let rows = lu.permutation();
+                std::ptr::copy_nonoverlapping(rows.as_ptr(), permutation, rows.len());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[no_mangle]
+/// Write the QR decomposition `A = QR` of `matrix` to `q` and `r`, to be freed with
+/// `complex_matrix_free`.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_qr(
+    matrix: *const ComplexMatrix,
+    q: *mut *mut ComplexMatrix,
+    r: *mut *mut ComplexMatrix,
+) {
+    unsafe {
+        let qr = (*matrix).qr();
+
+        *q = Box::into_raw(Box::new(qr.q));
+        *r = Box::into_raw(Box::new(qr.r));
+    }
+}
+
+#[no_mangle]
+/// Write the determinant of `matrix` to `determinant`, returning false if it isn't square.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_determinant(
+    matrix: *const ComplexMatrix,
+    determinant: *mut Complex,
+) -> bool {
+    unsafe {
+        match (*matrix).try_determinant() {
+            Ok(value) => {
+                *determinant = value;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_rank(matrix: *const ComplexMatrix) -> usize {
+    unsafe { (*matrix).rank() }
+}
+
+#[no_mangle]
+/// Returns the 2-norm condition number of `matrix`, or NaN if it couldn't be computed.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn complex_matrix_condition_number(matrix: *const ComplexMatrix) -> f32 {
+    unsafe { (*matrix).try_condition_number().unwrap_or(f32::NAN) }
+}
+
+#[no_mangle]
+/// Write the `width` eigenvalues of `matrix` to `eigenvalues`, returning false if it isn't
+/// square or if the QR algorithm didn't converge.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side, and `eigenvalues` must
+/// have room for `width` values.
+pub unsafe extern "C" fn complex_matrix_eigenvalues(
+    matrix: *const ComplexMatrix,
+    eigenvalues: *mut Complex,
+) -> bool {
+    unsafe {
+        match (*matrix).try_eigenvalues() {
+            Ok(values) => {
+                std::ptr::copy_nonoverlapping(values.as_ptr(), eigenvalues, values.len());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
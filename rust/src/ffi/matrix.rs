@@ -1,3 +1,6 @@
+use std::ffi::{c_char, CString};
+
+use super::into_raw_or_null;
 use crate::{matrix::Matrix, polynomial::Polynomial};
 
 #[no_mangle]
@@ -8,38 +11,121 @@ pub unsafe extern "C" fn matrix_new(
     width: usize,
     height: usize,
 ) -> *mut Matrix {
-    Box::into_raw(Box::new(Matrix::new(
-        Vec::from_raw_parts(content, width * height, width * height),
-        width,
-        height,
-    )))
+    unsafe {
+        into_raw_or_null(Matrix::try_new(
+            Vec::from_raw_parts(content, width * height, width * height),
+            width,
+            height,
+        ))
+    }
+}
+
+#[no_mangle]
+/// Create a matrix from `width * height` row-major polynomial pointers.
+///
+/// The polynomials are cloned, so the caller keeps ownership of `content`.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_from_polynomials(
+    content: *const *const Polynomial,
+    width: usize,
+    height: usize,
+) -> *mut Matrix {
+    unsafe {
+        let content = std::slice::from_raw_parts(content, width * height)
+            .iter()
+            .map(|&poly| (*poly).clone())
+            .collect();
+
+        into_raw_or_null(Matrix::try_new(content, width, height))
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_clone(matrix: *const Matrix) -> *mut Matrix {
+    unsafe { Box::into_raw(Box::new((*matrix).clone())) }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_width(matrix: *const Matrix) -> usize {
+    unsafe { (*matrix).width }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_height(matrix: *const Matrix) -> usize {
+    unsafe { (*matrix).height }
+}
+
+#[no_mangle]
+/// Returns a copy of the polynomial at (`row`, `column`), to be freed with `polynomial_free`,
+/// or null if the index is out of bounds.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_get(
+    matrix: *const Matrix,
+    row: usize,
+    column: usize,
+) -> *mut Polynomial {
+    unsafe {
+        match (*matrix).get(row, column) {
+            Some(poly) => Box::into_raw(Box::new(poly.clone())),
+            None => std::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+/// Returns the textual representation of `matrix`, to be freed with `string_free`.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn matrix_to_string(matrix: *const Matrix) -> *mut c_char {
+    unsafe {
+        CString::new((*matrix).to_string())
+            .expect("matrix display contains a NUL byte")
+            .into_raw()
+    }
 }
 
 #[no_mangle]
 /// # Safety
 /// None, as we drop an arbitrary address from the other side.
 pub unsafe extern "C" fn matrix_free(matrix: *mut Matrix) {
-    matrix.drop_in_place();
+    unsafe {
+        drop(Box::from_raw(matrix));
+    }
 }
 
 #[no_mangle]
 /// # Safety
 /// None, as we dereference raw pointers coming from the other side.
 pub unsafe extern "C" fn matrix_add(a: *mut Matrix, b: *mut Matrix) -> *mut Matrix {
-    let p1 = Box::from_raw(a);
-    let p2 = Box::from_raw(b);
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
 
-    Box::into_raw(Box::new(Matrix::add(&p1, &p2)))
+        into_raw_or_null(Matrix::try_add(&p1, &p2))
+    }
 }
 
 #[no_mangle]
 /// # Safety
 /// None, as we dereference raw pointers coming from the other side.
 pub unsafe extern "C" fn matrix_mul(a: *mut Matrix, b: *mut Matrix) -> *mut Matrix {
-    let p1 = Box::from_raw(a);
-    let p2 = Box::from_raw(b);
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
 
-    Box::into_raw(Box::new(Matrix::mul(&p1, &p2)))
+        into_raw_or_null(Matrix::try_mul(&p1, &p2))
+    }
 }
 
 #[no_mangle]
@@ -50,10 +136,12 @@ pub unsafe extern "C" fn matrix_add_in_ring(
     b: *mut Matrix,
     ring_degree: usize,
 ) -> *mut Matrix {
-    let p1 = Box::from_raw(a);
-    let p2 = Box::from_raw(b);
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
 
-    Box::into_raw(Box::new(Matrix::add_in_ring(&p1, &p2, ring_degree)))
+        into_raw_or_null(Matrix::try_add_in_ring(&p1, &p2, ring_degree))
+    }
 }
 
 #[no_mangle]
@@ -64,8 +152,10 @@ pub unsafe extern "C" fn matrix_mul_in_ring(
     b: *mut Matrix,
     ring_degree: usize,
 ) -> *mut Matrix {
-    let p1 = Box::from_raw(a);
-    let p2 = Box::from_raw(b);
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
 
-    Box::into_raw(Box::new(Matrix::mul_in_ring(&p1, &p2, ring_degree)))
+        into_raw_or_null(Matrix::try_mul_in_ring(&p1, &p2, ring_degree))
+    }
 }
@@ -1,3 +1,26 @@
 pub mod complex;
+pub mod linalg;
 pub mod matrix;
 pub mod polynomial;
+
+use std::ffi::{c_char, CString};
+
+use crate::error::Error;
+
+/// Fallible functions of the C API return a null pointer when the operation fails.
+fn into_raw_or_null<T>(result: Result<T, Error>) -> *mut T {
+    match result {
+        Ok(value) => Box::into_raw(Box::new(value)),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we take back ownership of a string previously returned by one of the
+/// `*_to_string` functions.
+pub unsafe extern "C" fn string_free(string: *mut c_char) {
+    unsafe {
+        drop(CString::from_raw(string));
+    }
+}
@@ -1,6 +1,13 @@
+use std::ffi::{c_char, CString};
+
 use rand::thread_rng;
 
-use crate::{complex::Complex, polynomial::Polynomial};
+use super::into_raw_or_null;
+use crate::{
+    complex::Complex,
+    polynomial::Polynomial,
+    random::{self, SeededRng},
+};
 
 #[no_mangle]
 /// # Safety
@@ -13,12 +20,83 @@ pub unsafe extern "C" fn polynomial_new(content: *mut Complex, len: usize) -> *m
     }
 }
 
+#[no_mangle]
+/// # Safety
+/// None, as we read `len` coefficients from an arbitrary address from the other side.
+/// The coefficients are copied, so the caller keeps ownership of `coefficients`.
+pub unsafe extern "C" fn polynomial_from_coefficients(
+    coefficients: *const Complex,
+    len: usize,
+) -> *mut Polynomial {
+    unsafe {
+        let coefficients = std::slice::from_raw_parts(coefficients, len);
+
+        Box::into_raw(Box::new(Polynomial::new(coefficients.to_vec())))
+    }
+}
+
+#[cfg(feature = "parse")]
+#[no_mangle]
+/// Parse a polynomial from a NUL-terminated string, such as `(1 + 2i)X2 + (-1 + 0i)`.
+///
+/// Returns a null pointer if the string isn't valid UTF-8 or doesn't contain any term.
+///
+/// # Safety
+/// None, as we read a C string from an arbitrary address from the other side.
+pub unsafe extern "C" fn polynomial_parse(text: *const c_char) -> *mut Polynomial {
+    unsafe {
+        let Ok(text) = std::ffi::CStr::from_ptr(text).to_str() else {
+            return std::ptr::null_mut();
+        };
+
+        into_raw_or_null(Polynomial::try_parse(text))
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_clone(poly: *const Polynomial) -> *mut Polynomial {
+    unsafe { Box::into_raw(Box::new((*poly).clone())) }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_degree(poly: *const Polynomial) -> usize {
+    unsafe { (*poly).degree }
+}
+
+#[no_mangle]
+/// Returns a pointer to the `degree + 1` coefficients of `poly`, from lowest to highest degree.
+///
+/// The pointer is borrowed from `poly` and is only valid until `poly` is freed.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_coefficients(poly: *const Polynomial) -> *const Complex {
+    unsafe { (*poly).coefficients.as_ptr() }
+}
+
+#[no_mangle]
+/// Returns the textual representation of `poly`, to be freed with `string_free`.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_to_string(poly: *const Polynomial) -> *mut c_char {
+    unsafe {
+        CString::new((*poly).to_string())
+            .expect("polynomial display contains a NUL byte")
+            .into_raw()
+    }
+}
+
 #[no_mangle]
 /// # Safety
 /// None, as we drop an arbitrary address from the other side.
 pub unsafe extern "C" fn polynomial_free(poly: *mut Polynomial) {
     unsafe {
-        poly.drop_in_place();
+        drop(Box::from_raw(poly));
     }
 }
 
@@ -29,6 +107,38 @@ pub extern "C" fn gen_random_polynomial() -> *mut Polynomial {
     Box::into_raw(Box::new(Polynomial::random(&mut rand)))
 }
 
+#[no_mangle]
+/// Generate a random polynomial from `seed`. The same seed gives the same polynomial on
+/// every platform.
+pub extern "C" fn gen_random_polynomial_seeded(seed: u64) -> *mut Polynomial {
+    let mut rand = random::seeded(seed);
+
+    Box::into_raw(Box::new(Polynomial::random(&mut rand)))
+}
+
+#[no_mangle]
+/// Create a seeded random number generator, to generate a reproducible sequence of
+/// polynomials with `gen_random_polynomial_from_rng`.
+pub extern "C" fn rng_new(seed: u64) -> *mut SeededRng {
+    Box::into_raw(Box::new(random::seeded(seed)))
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we drop an arbitrary address from the other side.
+pub unsafe extern "C" fn rng_free(rng: *mut SeededRng) {
+    unsafe {
+        drop(Box::from_raw(rng));
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn gen_random_polynomial_from_rng(rng: *mut SeededRng) -> *mut Polynomial {
+    unsafe { Box::into_raw(Box::new(Polynomial::random(&mut *rng))) }
+}
+
 #[no_mangle]
 /// # Safety
 /// None, as we dereference raw pointers coming from the other side.
@@ -65,7 +175,7 @@ pub unsafe extern "C" fn polynomial_add_in_ring(
         let p1 = Box::from_raw(a);
         let p2 = Box::from_raw(b);
 
-        Box::into_raw(Box::new(Polynomial::add_in_ring(&p1, &p2, ring_degree)))
+        into_raw_or_null(Polynomial::try_add_in_ring(&p1, &p2, ring_degree))
     }
 }
 
@@ -77,8 +187,64 @@ pub unsafe extern "C" fn polynomial_mul_in_ring(
     b: *mut Polynomial,
     ring_degree: usize,
 ) -> *mut Polynomial {
-    let p1 = Box::from_raw(a);
-    let p2 = Box::from_raw(b);
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
+
+        into_raw_or_null(Polynomial::try_mul_in_ring(&p1, &p2, ring_degree))
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_neg(poly: *mut Polynomial) -> *mut Polynomial {
+    unsafe {
+        let p = Box::from_raw(poly);
+
+        Box::into_raw(Box::new(p.neg()))
+    }
+}
+
+#[no_mangle]
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_reduce_to(
+    poly: *mut Polynomial,
+    ring_degree: usize,
+) -> *mut Polynomial {
+    unsafe {
+        let p = Box::from_raw(poly);
 
-    Box::into_raw(Box::new(Polynomial::mul_in_ring(&p1, &p2, ring_degree)))
+        into_raw_or_null(p.try_reduce_to(ring_degree))
+    }
+}
+
+#[no_mangle]
+/// Writes the quotient and the remainder of the euclidean division of `a` by `b`
+/// to `quotient` and `remainder`. Both are set to null pointers if `b` is zero.
+///
+/// # Safety
+/// None, as we dereference raw pointers coming from the other side.
+pub unsafe extern "C" fn polynomial_euclidean_division(
+    a: *mut Polynomial,
+    b: *mut Polynomial,
+    quotient: *mut *mut Polynomial,
+    remainder: *mut *mut Polynomial,
+) {
+    unsafe {
+        let p1 = Box::from_raw(a);
+        let p2 = Box::from_raw(b);
+
+        match Polynomial::try_euclidean_division(&p1, &p2) {
+            Ok((q, r)) => {
+                *quotient = Box::into_raw(Box::new(q));
+                *remainder = Box::into_raw(Box::new(r));
+            }
+            Err(_) => {
+                *quotient = std::ptr::null_mut();
+                *remainder = std::ptr::null_mut();
+            }
+        }
+    }
 }
@@ -0,0 +1,548 @@
+//! Finite fields, as coefficients for coding theory.
+//!
+//! The prime fields `GF(p)` are the [`Zq`] with a prime modulus. The binary extension fields
+//! `GF(2^m)` are polynomials over `GF(2)` modulo an irreducible polynomial of degree `m`,
+//! stored as bit masks: [`Gf2m`] multiplies bit by bit for any such polynomial, and
+//! [`Gf256`] uses log and antilog tables for the field with 256 elements used by
+//! Reed-Solomon codes.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use rand::Rng;
+
+use crate::{
+    error::Error,
+    modular::{is_prime, Zq},
+    polynomial::Polynomial,
+};
+
+/// The prime field `GF(P)`. `P` must be prime for every non-zero element to be invertible.
+pub type Gfp<const P: u32> = Zq<P>;
+
+/// An element of `GF(2^m)`, as a polynomial over `GF(2)` of degree less than `m` whose
+/// coefficients are the bits of `value`, modulo `POLY`, the bit mask of an irreducible
+/// polynomial of degree `m` such as `0b10011` for `x^4 + x + 1`.
+///
+/// `m` must be in `[1, 31]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Gf2m<const POLY: u32> {
+    value: u32,
+}
+
+impl<const POLY: u32> Gf2m<POLY> {
+    /// The degree `m` of the field over `GF(2)`. Evaluating it checks `POLY` at compile
+    /// time.
+    pub const DEGREE: u32 = {
+        assert!(
+            POLY >= 2,
+            "the field polynomial must have a degree of at least 1"
+        );
+        31 - POLY.leading_zeros()
+    };
+
+    pub const ZERO: Gf2m<POLY> = Gf2m { value: 0 };
+
+    pub const ONE: Gf2m<POLY> = Gf2m { value: 1 };
+
+    /// Returns the element whose bits are the coefficients of `value` reduced modulo `POLY`.
+    pub fn new(value: u32) -> Gf2m<POLY> {
+        Gf2m {
+            value: Self::reduce(value as u64),
+        }
+    }
+
+    /// The bits of the coefficients, lowest degree first.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Reduce a polynomial over `GF(2)` modulo `POLY`.
+    fn reduce(mut x: u64) -> u32 {
+        let degree = Self::DEGREE;
+
+        while x >> degree != 0 {
+            let shift = 63 - x.leading_zeros() - degree;
+            x ^= (POLY as u64) << shift;
+        }
+
+        x as u32
+    }
+
+    /// Returns `self` raised to the power `exponent`, by squaring.
+    pub fn pow(&self, mut exponent: u64) -> Gf2m<POLY> {
+        let mut base = *self;
+        let mut result = Self::ONE;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the multiplicative inverse with the extended Euclidean algorithm over
+    /// `GF(2)[x]`, or `None` for zero or if `POLY` isn't irreducible and shares a factor
+    /// with the element.
+    pub fn inv(&self) -> Option<Gf2m<POLY>> {
+        let (mut r0, mut r1) = (POLY as u64, self.value as u64);
+        let (mut t0, mut t1) = (0, 1);
+
+        while r1 != 0 {
+            let (quotient, remainder) = binary_division(r0, r1);
+            (r0, r1) = (r1, remainder);
+            (t0, t1) = (t1, t0 ^ Self::reduce(carryless_mul(quotient, t1)) as u64);
+        }
+
+        (r0 == 1).then(|| Gf2m::new(t0 as u32))
+    }
+
+    /// Generate an element uniformly distributed in the field.
+    pub fn random<R: Rng + ?Sized>(rand: &mut R) -> Gf2m<POLY> {
+        Gf2m {
+            value: rand.gen_range(0..1 << Self::DEGREE),
+        }
+    }
+}
+
+/// Returns the product of two polynomials over `GF(2)` given as bit masks, whose degrees
+/// add up to less than 64.
+fn carryless_mul(a: u64, b: u64) -> u64 {
+    (0..64)
+        .filter(|i| b >> i & 1 == 1)
+        .fold(0, |acc, i| acc ^ (a << i))
+}
+
+/// Returns the quotient and the remainder of the division of two polynomials over `GF(2)`
+/// given as bit masks, `b` being non-zero.
+fn binary_division(mut a: u64, b: u64) -> (u64, u64) {
+    let degree = 63 - b.leading_zeros();
+    let mut quotient = 0;
+
+    while a != 0 && 63 - a.leading_zeros() >= degree {
+        let shift = 63 - a.leading_zeros() - degree;
+        quotient ^= 1 << shift;
+        a ^= b << shift;
+    }
+
+    (quotient, a)
+}
+
+impl<const POLY: u32> Add for Gf2m<POLY> {
+    type Output = Gf2m<POLY>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf2m {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl<const POLY: u32> Sub for Gf2m<POLY> {
+    type Output = Gf2m<POLY>;
+
+    /// In characteristic 2, subtracting is adding.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+impl<const POLY: u32> Neg for Gf2m<POLY> {
+    type Output = Gf2m<POLY>;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl<const POLY: u32> Mul for Gf2m<POLY> {
+    type Output = Gf2m<POLY>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Gf2m {
+            value: Self::reduce(carryless_mul(self.value as u64, rhs.value as u64)),
+        }
+    }
+}
+
+impl<const POLY: u32> Display for Gf2m<POLY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#x}", self.value)
+    }
+}
+
+/// The polynomial `x^8 + x^4 + x^3 + x^2 + 1` defining [`Gf256`], for which `x` generates
+/// the multiplicative group.
+pub const GF256_POLY: u32 = 0x11d;
+
+/// The powers of the generator `x` of [`Gf256`], twice so that the sum of two logarithms
+/// doesn't need to be reduced modulo 255.
+const EXP: [u8; 512] = {
+    let mut table = [0; 512];
+    let mut value: u32 = 1;
+    let mut i = 0;
+    while i < 512 {
+        table[i] = value as u8;
+        value <<= 1;
+        if value & 0x100 != 0 {
+            value ^= GF256_POLY;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// The logarithms in base `x` of the non-zero elements of [`Gf256`].
+const LOG: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// An element of `GF(2^8)` modulo [`GF256_POLY`], multiplied through log and antilog
+/// tables. It's the same field as `Gf2m<GF256_POLY>`, with faster products.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Gf256 {
+    value: u8,
+}
+
+impl Gf256 {
+    pub const ZERO: Gf256 = Gf256 { value: 0 };
+
+    pub const ONE: Gf256 = Gf256 { value: 1 };
+
+    pub fn new(value: u8) -> Gf256 {
+        Gf256 { value }
+    }
+
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Returns `x^power`, `x` being the generator of the multiplicative group.
+    pub fn exp(power: usize) -> Gf256 {
+        Gf256 {
+            value: EXP[power % 255],
+        }
+    }
+
+    /// Returns the logarithm in base `x` of the element, in `[0, 255)`, or `None` for zero.
+    pub fn log(&self) -> Option<usize> {
+        (self.value != 0).then(|| LOG[self.value as usize] as usize)
+    }
+
+    /// Returns `self` raised to the power `exponent`.
+    pub fn pow(&self, exponent: usize) -> Gf256 {
+        match self.log() {
+            Some(log) => Gf256::exp(log * (exponent % 255)),
+            None if exponent == 0 => Gf256::ONE,
+            None => Gf256::ZERO,
+        }
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    pub fn inv(&self) -> Option<Gf256> {
+        self.log().map(|log| Gf256::exp(255 - log))
+    }
+
+    /// Generate an element uniformly distributed in the field.
+    pub fn random<R: Rng + ?Sized>(rand: &mut R) -> Gf256 {
+        Gf256 { value: rand.gen() }
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Gf256;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf256 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl Sub for Gf256 {
+    type Output = Gf256;
+
+    /// In characteristic 2, subtracting is adding.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+impl Neg for Gf256 {
+    type Output = Gf256;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Gf256;
+
+    /// Multiplies by adding the logarithms.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => Gf256 { value: EXP[a + b] },
+            _ => Gf256::ZERO,
+        }
+    }
+}
+
+impl Display for Gf256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#x}", self.value)
+    }
+}
+
+/// Returns the first monic irreducible polynomial of degree `degree` over `GF(P)`, ordering
+/// them by their coefficients read as a number in base `P`.
+///
+/// Panics if `degree` is 0 or `P` isn't prime, see [`try_irreducible_polynomial`].
+pub fn irreducible_polynomial<const P: u32>(degree: usize) -> Polynomial<Gfp<P>> {
+    try_irreducible_polynomial(degree).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Returns the first monic irreducible polynomial of degree `degree` over `GF(P)`, or an
+/// error if `degree` is 0 or `P` isn't prime. There always is one otherwise.
+pub fn try_irreducible_polynomial<const P: u32>(
+    degree: usize,
+) -> Result<Polynomial<Gfp<P>>, Error> {
+    if degree == 0 {
+        return Err(Error::InvalidDegree(degree));
+    }
+    if !is_prime(P) {
+        return Err(Error::NotPrime(P));
+    }
+
+    let mut coefficients = vec![Gfp::<P>::ZERO; degree + 1];
+    coefficients[degree] = Gfp::new(1);
+
+    loop {
+        let candidate = Polynomial::new(coefficients.clone());
+        if try_is_irreducible(&candidate)? {
+            return Ok(candidate);
+        }
+
+        // Count in base `P` on the lower coefficients.
+        for c in coefficients[..degree].iter_mut() {
+            *c = *c + Gfp::new(1);
+            if *c != Gfp::ZERO {
+                break;
+            }
+        }
+    }
+}
+
+/// Returns whether a monic polynomial over `GF(P)` is irreducible.
+///
+/// Panics if `P` isn't prime, see [`try_is_irreducible`].
+pub fn is_irreducible<const P: u32>(f: &Polynomial<Gfp<P>>) -> bool {
+    try_is_irreducible(f).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Returns whether a monic polynomial over `GF(P)` is irreducible, with Ben-Or's test: a
+/// polynomial `f` of degree `n` is irreducible if and only if it shares no factor with any
+/// `x^(P^i) - x` for `i <= n / 2`, the product of the irreducible polynomials whose degree
+/// divides `i`.
+///
+/// Returns an error if `P` isn't prime: the gcds need every non-zero coefficient to be
+/// invertible.
+pub fn try_is_irreducible<const P: u32>(f: &Polynomial<Gfp<P>>) -> Result<bool, Error> {
+    if !is_prime(P) {
+        return Err(Error::NotPrime(P));
+    }
+
+    let f = f.clone().trim();
+    let x = Polynomial::new(vec![Gfp::ZERO, Gfp::new(1)]);
+    let one = Polynomial::one();
+
+    // `x^(P^i)` modulo `f`, raising the previous one to the power `P` every step.
+    let mut power = x.clone();
+    for _ in 0..f.degree / 2 {
+        power = pow_mod(&power, P as u64, &f);
+        if Polynomial::gcd(&f, &Polynomial::sub(&power, &x)) != one {
+            return Ok(false);
+        }
+    }
+
+    Ok(f.degree >= 1)
+}
+
+/// Returns `base^exponent` modulo `modulus`, by squaring.
+fn pow_mod<const P: u32>(
+    base: &Polynomial<Gfp<P>>,
+    mut exponent: u64,
+    modulus: &Polynomial<Gfp<P>>,
+) -> Polynomial<Gfp<P>> {
+    let remainder = |p: &Polynomial<Gfp<P>>| Polynomial::euclidean_division(p, modulus).1;
+    let mut base = remainder(base);
+    let mut result = Polynomial::one();
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = remainder(&Polynomial::mul(&result, &base));
+        }
+        base = remainder(&Polynomial::mul(&base, &base));
+        exponent >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random;
+
+    /// Returns the polynomial over `GF(2)` whose coefficients are the bits of `mask`.
+    fn binary(mask: u32) -> Polynomial<Gfp<2>> {
+        Polynomial::new(
+            (0..32 - mask.leading_zeros())
+                .map(|i| Gfp::new((mask >> i & 1) as i64))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_gf256() {
+        // The tables agree with the bitwise products, and every non-zero element has an
+        // inverse.
+        for a in 0..=255 {
+            for b in 0..=255 {
+                assert_eq!(
+                    (Gf256::new(a) * Gf256::new(b)).value() as u32,
+                    (Gf2m::<GF256_POLY>::new(a as u32) * Gf2m::new(b as u32)).value()
+                );
+            }
+
+            let a = Gf256::new(a);
+            match a.inv() {
+                Some(inverse) => assert_eq!(Gf256::ONE, a * inverse),
+                None => assert_eq!(Gf256::ZERO, a),
+            }
+        }
+
+        let x = Gf256::new(2);
+        assert_eq!(Gf256::new(0x1d), x.pow(8));
+        assert_eq!(Some(8), Gf256::new(0x1d).log());
+        assert_eq!(Gf256::ONE, x.pow(255));
+        assert_eq!(Gf256::ZERO, Gf256::new(0x53) + Gf256::new(0x53));
+        assert_eq!("0x53", Gf256::new(0x53).to_string());
+    }
+
+    #[test]
+    fn test_gf2m() {
+        // GF(16) modulo x^4 + x + 1: x^4 = x + 1.
+        type Gf16 = Gf2m<0b10011>;
+        let x = Gf16::new(0b10);
+        assert_eq!(4, Gf16::DEGREE);
+        assert_eq!(Gf16::new(0b11), x.pow(4));
+        assert_eq!(Gf16::new(0b11), Gf16::new(0b10000));
+        assert_eq!(Gf16::ONE, x.pow(15));
+
+        for a in 1..16 {
+            let a = Gf16::new(a);
+            assert_eq!(Gf16::ONE, a * a.inv().unwrap());
+        }
+        assert_eq!(None, Gf16::ZERO.inv());
+
+        // x^4 + 1 = (x + 1)^4 isn't irreducible: x + 1 has no inverse.
+        assert_eq!(None, Gf2m::<0b10001>::new(0b11).inv());
+
+        let mut rand = random::seeded(46);
+        let a = Gf2m::<0x8000_0009>::random(&mut rand);
+        assert_eq!(Gf2m::ONE, a * a.inv().unwrap());
+    }
+
+    #[test]
+    fn test_polynomials_over_finite_fields() {
+        let poly = |coefficients: &[u8]| {
+            Polynomial::new(coefficients.iter().map(|&c| Gf256::new(c)).collect())
+        };
+
+        // (x + 3)(x + 7) and (x + 3)(x + 0x10) share the root 3 (in characteristic 2).
+        let common = poly(&[3, 1]);
+        let a = Polynomial::mul(&common, &poly(&[7, 1]));
+        let b = Polynomial::mul(&common, &poly(&[0x10, 1]));
+        assert_eq!(common, Polynomial::gcd(&a, &b));
+        assert_eq!(Gf256::ZERO, a.eval(Gf256::new(3)));
+        assert_ne!(Gf256::ZERO, a.eval(Gf256::new(4)));
+
+        let (quotient, remainder) = Polynomial::euclidean_division(&a, &poly(&[1, 0, 5]));
+        assert_eq!(
+            a,
+            Polynomial::add(&Polynomial::mul(&quotient, &poly(&[1, 0, 5])), &remainder)
+        );
+
+        // Over GF(7): (x - 2)(x - 3) and (x - 3)(x + 1).
+        let gf7 = |coefficients: &[i64]| {
+            Polynomial::new(coefficients.iter().map(|&c| Gfp::<7>::new(c)).collect())
+        };
+        assert_eq!(
+            gf7(&[-3, 1]),
+            Polynomial::gcd(&gf7(&[6, -5, 1]), &gf7(&[-3, -2, 1]))
+        );
+    }
+
+    #[test]
+    fn test_irreducible_polynomials() {
+        // The first irreducible polynomials of degree 4 and 8 over GF(2): x^4 + x + 1 and
+        // the AES polynomial x^8 + x^4 + x^3 + x + 1.
+        assert_eq!(binary(0b10011), irreducible_polynomial::<2>(4));
+        assert_eq!(binary(0x11b), irreducible_polynomial::<2>(8));
+        assert!(is_irreducible(&binary(GF256_POLY)));
+        assert!(!is_irreducible(&binary(0b10001)));
+        assert_eq!(binary(0b10), irreducible_polynomial::<2>(1));
+
+        // x^2 + 1 has no root modulo 3, but x^2 + 1 = (x + 2)(x + 3) modulo 5.
+        assert_eq!(
+            Polynomial::new(vec![Gfp::new(1), Gfp::ZERO, Gfp::new(1)]),
+            irreducible_polynomial::<3>(2)
+        );
+        let f = irreducible_polynomial::<5>(2);
+        assert_ne!(
+            Polynomial::new(vec![Gfp::new(1), Gfp::ZERO, Gfp::new(1)]),
+            f
+        );
+        assert!((0..5).all(|x| f.eval(Gfp::new(x)) != Gfp::ZERO));
+
+        // Irreducible polynomials of degree 8 are a bit less than 1/8th of the monic ones.
+        let count = (0..256)
+            .filter(|&mask| is_irreducible(&binary(0x100 | mask)))
+            .count();
+        assert_eq!(30, count);
+
+        assert_eq!(
+            Err(Error::InvalidDegree(0)),
+            try_irreducible_polynomial::<2>(0)
+        );
+
+        // The gcds need a field: composite moduli are rejected rather than looping forever.
+        assert_eq!(Err(Error::NotPrime(4)), try_irreducible_polynomial::<4>(2));
+        assert_eq!(
+            Err(Error::NotPrime(6)),
+            try_is_irreducible(&Polynomial::new(vec![
+                Gfp::<6>::new(1),
+                Gfp::ZERO,
+                Gfp::new(1)
+            ]))
+        );
+    }
+}
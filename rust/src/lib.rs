@@ -1,7 +1,29 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 
+pub mod approx;
+mod circulant;
 pub mod complex;
+mod complex64;
+pub mod dft;
+pub mod element;
+pub mod error;
+mod evaluation;
+pub mod exact;
+pub mod finite_field;
+pub mod linalg;
+pub mod lwe;
 pub mod matrix;
+pub mod modular;
+pub mod normal_form;
+pub mod ntt;
 pub mod polynomial;
+pub mod random;
+pub mod reed_solomon;
+mod roots;
+pub mod shamir;
+#[cfg(test)]
+mod test_util;
+pub mod vector;
+pub mod view;
 
 pub mod ffi;
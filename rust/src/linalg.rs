@@ -0,0 +1,800 @@
+//! Numeric linear algebra on dense complex matrices: LU and QR decompositions, linear
+//! systems, rank, condition number and eigenvalues.
+//!
+//! [`Complex`] stores `f32` parts, but every algorithm here works on `f64` copies and only
+//! rounds its results back, so that errors don't pile up over the `O(n^3)` operations.
+
+use crate::{complex::Complex, complex64::C64, error::Error, matrix::Matrix};
+
+/// A dense matrix of complex numbers, the [`Matrix`] used for numeric linear algebra.
+pub type ComplexMatrix = Matrix<Complex>;
+
+/// Maximum number of QR iterations per eigenvalue before giving up.
+const MAX_QR_ITERATIONS: usize = 100;
+
+/// A row-major working copy of a matrix, in double precision.
+#[derive(Debug, Clone)]
+struct Dense {
+    rows: usize,
+    columns: usize,
+    data: Vec<C64>,
+}
+
+impl Dense {
+    fn from_matrix(m: &ComplexMatrix) -> Dense {
+        Dense {
+            rows: m.height,
+            columns: m.width,
+            data: m.content.iter().map(|&c| c.into()).collect(),
+        }
+    }
+
+    fn identity(n: usize) -> Dense {
+        let mut data = vec![C64::ZERO; n * n];
+        for i in 0..n {
+            data[i * (n + 1)] = C64::ONE;
+        }
+
+        Dense {
+            rows: n,
+            columns: n,
+            data,
+        }
+    }
+
+    fn to_matrix(&self) -> ComplexMatrix {
+        Matrix::new(
+            self.data.iter().map(|&c| c.into()).collect(),
+            self.columns,
+            self.rows,
+        )
+    }
+
+    fn at(&self, i: usize, j: usize) -> C64 {
+        self.data[j + i * self.columns]
+    }
+
+    fn at_mut(&mut self, i: usize, j: usize) -> &mut C64 {
+        &mut self.data[j + i * self.columns]
+    }
+
+    fn largest_norm(&self) -> f64 {
+        self.data.iter().map(|c| c.norm()).fold(0.0, f64::max)
+    }
+
+    /// Returns the Householder vector `v` of unit norm such that `(I - 2vv*) x` is zero
+    /// except for its first element, or `None` if `x` is already zero.
+    fn householder(x: &[C64]) -> Option<Vec<C64>> {
+        let norm = x
+            .iter()
+            .map(|c| c.re * c.re + c.im * c.im)
+            .sum::<f64>()
+            .sqrt();
+        if norm == 0.0 {
+            return None;
+        }
+
+        // Reflecting onto `-e^(i arg x0) |x|` avoids the cancellation in `x0 - alpha`.
+        let phase = if x[0].norm() == 0.0 {
+            C64::ONE
+        } else {
+            x[0].scale(1.0 / x[0].norm())
+        };
+        let alpha = -phase.scale(norm);
+
+        let mut v = x.to_vec();
+        v[0] = v[0] - alpha;
+
+        let v_norm = v
+            .iter()
+            .map(|c| c.re * c.re + c.im * c.im)
+            .sum::<f64>()
+            .sqrt();
+        if v_norm == 0.0 {
+            return None;
+        }
+
+        Some(v.into_iter().map(|c| c.scale(1.0 / v_norm)).collect())
+    }
+
+    /// Apply `I - 2vv*` from the left to the rows `start..start + v.len()`, on the columns
+    /// `columns`.
+    fn reflect_rows(&mut self, v: &[C64], start: usize, columns: std::ops::Range<usize>) {
+        for j in columns {
+            let dot = v.iter().enumerate().fold(C64::ZERO, |acc, (k, vk)| {
+                acc + vk.conj() * self.at(start + k, j)
+            });
+
+            for (k, vk) in v.iter().enumerate() {
+                let value = self.at(start + k, j) - (*vk * dot).scale(2.0);
+                *self.at_mut(start + k, j) = value;
+            }
+        }
+    }
+
+    /// Apply `I - 2vv*` from the right to the columns `start..start + v.len()`, on the rows
+    /// `rows`.
+    fn reflect_columns(&mut self, v: &[C64], start: usize, rows: std::ops::Range<usize>) {
+        for i in rows {
+            let dot = v
+                .iter()
+                .enumerate()
+                .fold(C64::ZERO, |acc, (k, vk)| acc + self.at(i, start + k) * *vk);
+
+            for (k, vk) in v.iter().enumerate() {
+                let value = self.at(i, start + k) - (dot * vk.conj()).scale(2.0);
+                *self.at_mut(i, start + k) = value;
+            }
+        }
+    }
+
+    /// Reduce a square matrix to upper Hessenberg form, with the same eigenvalues.
+    fn hessenberg(&mut self) {
+        let n = self.rows;
+
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<C64> = (k + 1..n).map(|i| self.at(i, k)).collect();
+
+            if let Some(v) = Dense::householder(&x) {
+                self.reflect_rows(&v, k + 1, k..n);
+                self.reflect_columns(&v, k + 1, 0..n);
+            }
+
+            for i in k + 2..n {
+                *self.at_mut(i, k) = C64::ZERO;
+            }
+        }
+    }
+
+    /// Returns the eigenvalues of an upper Hessenberg matrix, with the shifted QR algorithm.
+    fn hessenberg_eigenvalues(mut self) -> Result<Vec<C64>, Error> {
+        let mut eigenvalues = Vec::with_capacity(self.rows);
+        let mut hi = self.rows;
+        let scale = self.largest_norm().max(f64::MIN_POSITIVE);
+        let mut iterations = 0;
+
+        while hi > 0 {
+            if hi == 1 {
+                eigenvalues.push(self.at(0, 0));
+                break;
+            }
+
+            // Find the start of the unreduced block ending at `hi - 1`, zeroing negligible
+            // subdiagonal elements on the way.
+            let mut lo = hi - 1;
+            while lo > 0 {
+                let neighbours = self.at(lo, lo).norm() + self.at(lo - 1, lo - 1).norm();
+                if self.at(lo, lo - 1).norm() <= f64::EPSILON * neighbours.max(scale * 1e-3) {
+                    *self.at_mut(lo, lo - 1) = C64::ZERO;
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi - 1 {
+                eigenvalues.push(self.at(hi - 1, hi - 1));
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > MAX_QR_ITERATIONS {
+                return Err(Error::NoConvergence);
+            }
+
+            let shift = if iterations.is_multiple_of(11) {
+                // Exceptional shift, to break the rare cycles of the Wilkinson shift.
+                self.at(hi - 1, hi - 1) + C64::new(self.at(hi - 1, hi - 2).norm(), 0.0)
+            } else {
+                self.wilkinson_shift(hi)
+            };
+
+            self.qr_step(lo, hi, shift);
+        }
+
+        Ok(eigenvalues)
+    }
+
+    /// Returns the eigenvalue of the trailing 2x2 block of the active window `..hi` closest
+    /// to its last diagonal element.
+    fn wilkinson_shift(&self, hi: usize) -> C64 {
+        let a = self.at(hi - 2, hi - 2);
+        let b = self.at(hi - 2, hi - 1);
+        let c = self.at(hi - 1, hi - 2);
+        let d = self.at(hi - 1, hi - 1);
+
+        let half_trace = (a + d).scale(0.5);
+        let discriminant = ((a - d).scale(0.5) * (a - d).scale(0.5) + b * c).sqrt();
+
+        let l1 = half_trace + discriminant;
+        let l2 = half_trace - discriminant;
+
+        if (l1 - d).norm() < (l2 - d).norm() {
+            l1
+        } else {
+            l2
+        }
+    }
+
+    /// One shifted QR step `H - sI = QR, H <- RQ + sI` on the block `lo..hi`, with Givens
+    /// rotations.
+    fn qr_step(&mut self, lo: usize, hi: usize, shift: C64) {
+        for k in lo..hi {
+            *self.at_mut(k, k) = self.at(k, k) - shift;
+        }
+
+        let mut rotations = Vec::with_capacity(hi - lo - 1);
+
+        for k in lo..hi - 1 {
+            let a = self.at(k, k);
+            let b = self.at(k + 1, k);
+            let r = a.norm().hypot(b.norm());
+
+            let (c, s) = if r == 0.0 {
+                (1.0, C64::ZERO)
+            } else if a.norm() == 0.0 {
+                (0.0, C64::ONE)
+            } else {
+                (
+                    a.norm() / r,
+                    a.scale(1.0 / a.norm()) * b.conj().scale(1.0 / r),
+                )
+            };
+
+            for j in k..hi {
+                let x = self.at(k, j);
+                let y = self.at(k + 1, j);
+
+                *self.at_mut(k, j) = x.scale(c) + s * y;
+                *self.at_mut(k + 1, j) = -(s.conj() * x) + y.scale(c);
+            }
+
+            rotations.push((c, s));
+        }
+
+        for (k, (c, s)) in (lo..hi - 1).zip(rotations) {
+            for i in lo..(k + 2).min(hi) {
+                let x = self.at(i, k);
+                let y = self.at(i, k + 1);
+
+                *self.at_mut(i, k) = x.scale(c) + y * s.conj();
+                *self.at_mut(i, k + 1) = -(x * s) + y.scale(c);
+            }
+        }
+
+        for k in lo..hi {
+            *self.at_mut(k, k) = self.at(k, k) + shift;
+        }
+    }
+}
+
+/// An LU decomposition with partial pivoting, `PA = LU`.
+#[derive(Debug, Clone)]
+pub struct Lu {
+    n: usize,
+    /// `L` below the diagonal, with an implicit unit diagonal, and `U` above.
+    factors: Dense,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl Lu {
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> ComplexMatrix {
+        let mut l = Dense::identity(self.n);
+        for i in 0..self.n {
+            for j in 0..i {
+                *l.at_mut(i, j) = self.factors.at(i, j);
+            }
+        }
+
+        l.to_matrix()
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> ComplexMatrix {
+        let mut u = self.factors.clone();
+        for i in 0..self.n {
+            for j in 0..i {
+                *u.at_mut(i, j) = C64::ZERO;
+            }
+        }
+
+        u.to_matrix()
+    }
+
+    /// Returns the row permutation: the `i`-th row of `PA` is the `permutation()[i]`-th row
+    /// of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> Complex {
+        let product = (0..self.n).fold(C64::ONE, |acc, i| acc * self.factors.at(i, i));
+
+        if self.swaps.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+        .into()
+    }
+
+    /// Solve `AX = B` for `X`, returning an error if `A` is singular or if `B` doesn't have
+    /// as many rows as `A`.
+    pub fn solve(&self, b: &ComplexMatrix) -> Result<ComplexMatrix, Error> {
+        if b.height != self.n {
+            return Err(Error::DimensionMismatch {
+                left: (self.n, self.n),
+                right: (b.width, b.height),
+            });
+        }
+
+        let singular_threshold = self.factors.largest_norm() * f64::EPSILON * self.n as f64;
+        if (0..self.n).any(|i| self.factors.at(i, i).norm() <= singular_threshold) {
+            return Err(Error::NotInvertible);
+        }
+
+        let b = Dense::from_matrix(b);
+        let mut x = Dense {
+            rows: self.n,
+            columns: b.columns,
+            data: Vec::with_capacity(b.data.len()),
+        };
+        for &row in &self.permutation {
+            x.data
+                .extend_from_slice(&b.data[row * b.columns..(row + 1) * b.columns]);
+        }
+
+        for column in 0..x.columns {
+            // Forward substitution with L, then back substitution with U.
+            for i in 0..self.n {
+                let sum = (0..i).fold(x.at(i, column), |acc, k| {
+                    acc - self.factors.at(i, k) * x.at(k, column)
+                });
+                *x.at_mut(i, column) = sum;
+            }
+
+            for i in (0..self.n).rev() {
+                let sum = (i + 1..self.n).fold(x.at(i, column), |acc, k| {
+                    acc - self.factors.at(i, k) * x.at(k, column)
+                });
+                *x.at_mut(i, column) = sum / self.factors.at(i, i);
+            }
+        }
+
+        Ok(x.to_matrix())
+    }
+}
+
+/// A QR decomposition `A = QR`, with `Q` unitary and `R` upper triangular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr {
+    pub q: ComplexMatrix,
+    pub r: ComplexMatrix,
+}
+
+impl ComplexMatrix {
+    /// Returns the LU decomposition with partial pivoting of a square matrix.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_lu`].
+    pub fn lu(&self) -> Lu {
+        self.try_lu().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the LU decomposition with partial pivoting of the matrix, or an error if it
+    /// isn't square. Singular matrices are decomposed too, with zeros on the diagonal of `U`.
+    pub fn try_lu(&self) -> Result<Lu, Error> {
+        if self.width != self.height {
+            return Err(Error::NotSquare((self.width, self.height)));
+        }
+
+        let n = self.width;
+        let mut factors = Dense::from_matrix(self);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| factors.at(i, k).norm().total_cmp(&factors.at(j, k).norm()))
+                .unwrap_or(k);
+
+            if pivot != k {
+                for j in 0..n {
+                    factors.data.swap(j + k * n, j + pivot * n);
+                }
+                permutation.swap(k, pivot);
+                swaps += 1;
+            }
+
+            let diagonal = factors.at(k, k);
+            if diagonal.norm() == 0.0 {
+                continue;
+            }
+
+            for i in k + 1..n {
+                let factor = factors.at(i, k) / diagonal;
+                *factors.at_mut(i, k) = factor;
+
+                for j in k + 1..n {
+                    let value = factors.at(i, j) - factor * factors.at(k, j);
+                    *factors.at_mut(i, j) = value;
+                }
+            }
+        }
+
+        Ok(Lu {
+            n,
+            factors,
+            permutation,
+            swaps,
+        })
+    }
+
+    /// Returns the QR decomposition of the matrix, computed with Householder reflections.
+    /// For a `width`x`height` matrix, `Q` is `height`x`height` and `R` is `width`x`height`.
+    pub fn qr(&self) -> Qr {
+        let (m, n) = (self.height, self.width);
+        let mut r = Dense::from_matrix(self);
+        // Accumulating the reflections on the rows of `Q*` keeps them all row operations.
+        let mut q_adjoint = Dense::identity(m);
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            let x: Vec<C64> = (k..m).map(|i| r.at(i, k)).collect();
+
+            if let Some(v) = Dense::householder(&x) {
+                r.reflect_rows(&v, k, k..n);
+                q_adjoint.reflect_rows(&v, k, 0..m);
+            }
+
+            for i in k + 1..m {
+                *r.at_mut(i, k) = C64::ZERO;
+            }
+        }
+
+        Qr {
+            q: q_adjoint.to_matrix().conjugate_transpose(),
+            r: r.to_matrix(),
+        }
+    }
+
+    /// Solve the linear system `AX = B` for `X`, `B` having one column per right-hand side.
+    ///
+    /// Panics if `A` isn't square or is singular, see [`Matrix::try_solve`].
+    pub fn solve(a: &ComplexMatrix, b: &ComplexMatrix) -> ComplexMatrix {
+        Self::try_solve(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Solve the linear system `AX = B` for `X` with an LU decomposition, returning an error
+    /// if `A` isn't square, if it's singular, or if `B` doesn't have as many rows as `A`.
+    pub fn try_solve(a: &ComplexMatrix, b: &ComplexMatrix) -> Result<ComplexMatrix, Error> {
+        a.try_lu()?.solve(b)
+    }
+
+    /// Returns the determinant of a square matrix.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_determinant`].
+    pub fn determinant(&self) -> Complex {
+        self.try_determinant().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the determinant of the matrix from its LU decomposition, or an error if it
+    /// isn't square.
+    pub fn try_determinant(&self) -> Result<Complex, Error> {
+        Ok(self.try_lu()?.determinant())
+    }
+
+    /// Returns the rank of the matrix, the number of its linearly independent rows.
+    ///
+    /// It's computed with a Gaussian elimination with complete pivoting, treating pivots
+    /// below `max(width, height) * f32::EPSILON` times the largest element as zeros, since the
+    /// elements are only accurate to `f32` precision.
+    pub fn rank(&self) -> usize {
+        let mut m = Dense::from_matrix(self);
+        let threshold = m.largest_norm() * f32::EPSILON as f64 * self.width.max(self.height) as f64;
+
+        let mut rank = 0;
+        let mut rows: Vec<usize> = (0..m.rows).collect();
+        let mut columns: Vec<usize> = (0..m.columns).collect();
+
+        while rank < rows.len().min(columns.len()) {
+            let mut pivot = (rank, rank);
+            for i in rank..rows.len() {
+                for j in rank..columns.len() {
+                    if m.at(rows[i], columns[j]).norm()
+                        > m.at(rows[pivot.0], columns[pivot.1]).norm()
+                    {
+                        pivot = (i, j);
+                    }
+                }
+            }
+
+            let pivot_value = m.at(rows[pivot.0], columns[pivot.1]);
+            if pivot_value.norm() <= threshold {
+                break;
+            }
+
+            rows.swap(rank, pivot.0);
+            columns.swap(rank, pivot.1);
+
+            for &row in &rows[rank + 1..] {
+                let factor = m.at(row, columns[rank]) / pivot_value;
+
+                for &column in &columns[rank..] {
+                    let value = m.at(row, column) - factor * m.at(rows[rank], column);
+                    *m.at_mut(row, column) = value;
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// Returns the eigenvalues of a square matrix, in no particular order.
+    ///
+    /// Panics if the matrix isn't square or if the QR algorithm doesn't converge, see
+    /// [`Matrix::try_eigenvalues`].
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        self.try_eigenvalues().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the eigenvalues of the matrix, in no particular order, or an error if it isn't
+    /// square or if the QR algorithm doesn't converge.
+    ///
+    /// The matrix is first reduced to Hessenberg form, then eigenvalues are deflated one by
+    /// one with Wilkinson-shifted QR steps.
+    pub fn try_eigenvalues(&self) -> Result<Vec<Complex>, Error> {
+        if self.width != self.height {
+            return Err(Error::NotSquare((self.width, self.height)));
+        }
+
+        let mut h = Dense::from_matrix(self);
+        h.hessenberg();
+
+        Ok(h.hessenberg_eigenvalues()?
+            .into_iter()
+            .map(Complex::from)
+            .collect())
+    }
+
+    /// Returns the singular values of the matrix, from the largest to the smallest.
+    ///
+    /// They're the square roots of the eigenvalues of `A*A`, see [`Matrix::try_eigenvalues`]
+    /// for the errors.
+    pub fn try_singular_values(&self) -> Result<Vec<f32>, Error> {
+        let gram = Matrix::mul(&self.conjugate_transpose(), self);
+
+        let mut h = Dense::from_matrix(&gram);
+        h.hessenberg();
+
+        // `A*A` is Hermitian and positive semi-definite: its eigenvalues are real and
+        // non-negative, up to rounding errors.
+        let mut values: Vec<f64> = h
+            .hessenberg_eigenvalues()?
+            .into_iter()
+            .map(|lambda| lambda.re.max(0.0).sqrt())
+            .collect();
+        values.sort_by(|a, b| b.total_cmp(a));
+
+        Ok(values.into_iter().map(|v| v as f32).collect())
+    }
+
+    /// Returns the 2-norm condition number of the matrix, the ratio of its largest singular
+    /// value to its smallest one. Singular matrices have an infinite condition number.
+    ///
+    /// Panics if the QR algorithm doesn't converge, see [`Matrix::try_condition_number`].
+    pub fn condition_number(&self) -> f32 {
+        self.try_condition_number()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the 2-norm condition number of the matrix, or an error if the QR algorithm
+    /// doesn't converge.
+    pub fn try_condition_number(&self) -> Result<f32, Error> {
+        let values = self.try_singular_values()?;
+        let (largest, smallest) = (values[0], values[values.len() - 1]);
+
+        Ok(if smallest == 0.0 {
+            f32::INFINITY
+        } else {
+            largest / smallest
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        approx::Tolerance,
+        assert_approx_eq,
+        random::{self, CoefficientDistribution},
+    };
+
+    fn matrix(values: &[(f32, f32)], width: usize, height: usize) -> ComplexMatrix {
+        Matrix::new(
+            values
+                .iter()
+                .map(|&(re, im)| Complex::new(re, im))
+                .collect(),
+            width,
+            height,
+        )
+    }
+
+    fn random_matrix(width: usize, height: usize, seed: u64) -> ComplexMatrix {
+        let mut rand = random::seeded(seed);
+        let distribution = CoefficientDistribution::UniformBox { bound: 1.0 };
+
+        Matrix::new(
+            (0..width * height)
+                .map(|_| distribution.sample(&mut rand))
+                .collect(),
+            width,
+            height,
+        )
+    }
+
+    fn sorted(mut values: Vec<Complex>) -> Vec<Complex> {
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        values
+    }
+
+    #[test]
+    fn test_lu() {
+        let a = random_matrix(5, 5, 0);
+        let lu = a.lu();
+
+        let permuted = Matrix::new(
+            lu.permutation()
+                .iter()
+                .flat_map(|&row| a.content[row * 5..(row + 1) * 5].to_vec())
+                .collect(),
+            5,
+            5,
+        );
+        assert_approx_eq!(
+            permuted,
+            Matrix::mul(&lu.l(), &lu.u()),
+            Tolerance::new().absolute(1e-5)
+        );
+
+        // [[1, 2], [3, 4]] has the determinant -2
+        let a = matrix(&[(1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)], 2, 2);
+        assert_approx_eq!(Complex::new(-2.0, 0.0), a.determinant());
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = random_matrix(4, 4, 1);
+        let x = random_matrix(2, 4, 2);
+        let b = Matrix::mul(&a, &x);
+
+        assert_approx_eq!(x, Matrix::solve(&a, &b), Tolerance::new().absolute(1e-4));
+
+        let singular = matrix(&[(1.0, 1.0), (2.0, 2.0), (2.0, 2.0), (4.0, 4.0)], 2, 2);
+        assert_eq!(
+            Err(Error::NotInvertible),
+            Matrix::try_solve(&singular, &random_matrix(1, 2, 3))
+        );
+        assert!(Matrix::try_solve(&a, &random_matrix(1, 3, 3)).is_err());
+    }
+
+    #[test]
+    fn test_qr() {
+        for (width, height) in [(4, 4), (3, 5), (5, 3)] {
+            let a = random_matrix(width, height, 4);
+            let Qr { q, r } = a.qr();
+
+            let tolerance = Tolerance::new().absolute(1e-5);
+            assert_approx_eq!(a, Matrix::mul(&q, &r), tolerance);
+            assert_approx_eq!(
+                Matrix::identity(height),
+                Matrix::mul(&q.conjugate_transpose(), &q),
+                tolerance
+            );
+            for i in 0..r.height {
+                for j in 0..i.min(r.width) {
+                    assert_eq!(Complex::ZERO, r[(i, j)]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(4, random_matrix(4, 4, 5).rank());
+        assert_eq!(3, random_matrix(3, 5, 5).rank());
+
+        // The third row is the sum of the first two.
+        let a = matrix(
+            &[
+                (1.0, 0.0),
+                (2.0, 1.0),
+                (0.0, 1.0),
+                (3.0, 0.0),
+                (0.0, 0.0),
+                (1.0, -1.0),
+                (4.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 0.0),
+            ],
+            3,
+            3,
+        );
+        assert_eq!(2, a.rank());
+        assert_eq!(0, matrix(&[(0.0, 0.0); 4], 2, 2).rank());
+    }
+
+    #[test]
+    fn test_eigenvalues() {
+        // A triangular matrix has its diagonal as eigenvalues.
+        let a = matrix(
+            &[
+                (2.0, 0.0),
+                (1.0, 5.0),
+                (3.0, 0.0),
+                (0.0, 0.0),
+                (-1.0, 1.0),
+                (2.0, 2.0),
+                (0.0, 0.0),
+                (0.0, 0.0),
+                (0.5, 0.0),
+            ],
+            3,
+            3,
+        );
+        let expected = sorted(vec![
+            Complex::new(-1.0, 1.0),
+            Complex::new(0.5, 0.0),
+            Complex::new(2.0, 0.0),
+        ]);
+        let tolerance = Tolerance::new().absolute(1e-5);
+        assert_approx_eq!(expected, sorted(a.eigenvalues()), tolerance);
+
+        // A real rotation has the eigenvalues +i and -i.
+        let rotation = matrix(&[(0.0, 0.0), (-1.0, 0.0), (1.0, 0.0), (0.0, 0.0)], 2, 2);
+        assert_approx_eq!(
+            vec![Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)],
+            sorted(rotation.eigenvalues()),
+            tolerance
+        );
+
+        // Every eigenvalue of a random matrix is a root of its characteristic polynomial, and
+        // their sum is the trace.
+        let a = random_matrix(6, 6, 6);
+        let values = a.eigenvalues();
+        let characteristic = a.characteristic_polynomial();
+
+        assert_eq!(6, values.len());
+        for v in &values {
+            assert!(characteristic.eval(*v).norm() < 1e-3);
+        }
+        let sum = values.iter().fold(Complex::ZERO, |acc, v| acc + *v);
+        assert_approx_eq!(a.trace(), sum, Tolerance::new().absolute(1e-4));
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let identity = ComplexMatrix::identity(3);
+        assert_approx_eq!(
+            1.0,
+            identity.condition_number(),
+            Tolerance::new().absolute(1e-5)
+        );
+
+        let diagonal = matrix(&[(4.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.5)], 2, 2);
+        assert_approx_eq!(
+            8.0,
+            diagonal.condition_number(),
+            Tolerance::new().absolute(1e-4)
+        );
+
+        let singular = matrix(&[(1.0, 0.0), (2.0, 0.0), (2.0, 0.0), (4.0, 0.0)], 2, 2);
+        assert!(singular.condition_number().is_infinite());
+    }
+}
@@ -0,0 +1,390 @@
+//! A toy public-key encryption scheme based on the module learning with errors problem,
+//! in the style of Kyber, over the ring `R_q = Z_q[x]/(x^n + 1)` with `q = 3329`.
+//!
+//! **This isn't production cryptography, it's only meant to show how the ring matrices of
+//! this crate fit together.** Nothing runs in constant time, secrets aren't wiped from
+//! memory, ciphertexts aren't compressed, the scheme is only secure against passive
+//! attackers (there is no transform to resist chosen ciphertexts), the parameter sets
+//! haven't been analyzed, and the randomness is whatever generator the caller provides.
+//!
+//! With `rank` the size of the module, the secret key is a vector `s` of `rank` small
+//! polynomials, and the public key a uniform `rank`x`rank` matrix `A` with `t = A s + e`,
+//! for another small vector `e`. A message of `n` bits is encrypted with fresh small `r`,
+//! `e1` and `e2` as `u = A^T r + e1` and `v = t^T r + e2 + ⌈q/2⌋ m`. Then
+//! `v - s^T u = e^T r + e2 - s^T e1 + ⌈q/2⌋ m`, where the noise stays far below `q/4`,
+//! so every coefficient rounds back to its bit.
+
+use rand::Rng;
+
+use crate::{error::Error, matrix::Matrix, modular::Zq, polynomial::Polynomial};
+
+/// The modulus of the coefficients, the one of Kyber.
+pub const MODULUS: u32 = 3329;
+
+/// The coefficients of `R_q`.
+pub type Coefficient = Zq<MODULUS>;
+
+/// The sizes of the scheme: the degree `n` of the ring, the rank of the module, and the
+/// width `eta` of the centered binomial distribution the noise is sampled from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    ring: usize,
+    rank: usize,
+    eta: u32,
+}
+
+impl Parameters {
+    /// A tiny ring-LWE instance, fast enough for examples and tests.
+    pub const TOY: Parameters = Parameters {
+        ring: 64,
+        rank: 1,
+        eta: 2,
+    };
+
+    /// Ring-LWE over a ring of degree 256.
+    pub const RLWE_256: Parameters = Parameters {
+        ring: 256,
+        rank: 1,
+        eta: 2,
+    };
+
+    /// Module-LWE of rank 2, with the sizes of Kyber-512. Its wider `η₁ = 3` is used for all
+    /// the noise, since the scheme has a single width.
+    pub const MLWE_512: Parameters = Parameters {
+        ring: 256,
+        rank: 2,
+        eta: 3,
+    };
+
+    /// Module-LWE of rank 3, with the sizes of Kyber-768.
+    pub const MLWE_768: Parameters = Parameters {
+        ring: 256,
+        rank: 3,
+        eta: 2,
+    };
+
+    /// Module-LWE of rank 4, with the sizes of Kyber-1024.
+    pub const MLWE_1024: Parameters = Parameters {
+        ring: 256,
+        rank: 4,
+        eta: 2,
+    };
+
+    /// Create custom parameters.
+    ///
+    /// Panics if they are out of range, see [`Parameters::try_new`].
+    pub fn new(ring: usize, rank: usize, eta: u32) -> Parameters {
+        Self::try_new(ring, rank, eta).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create custom parameters, or returns an error if `ring` isn't a non-zero multiple of
+    /// 8 (messages are whole bytes), if `rank` is 0, or if `eta` isn't in `[1, 32]`.
+    ///
+    /// Decryption only succeeds while the noise stays below `q/4`: large ranks and widths
+    /// make it fail.
+    pub fn try_new(ring: usize, rank: usize, eta: u32) -> Result<Parameters, Error> {
+        if ring == 0 || !ring.is_multiple_of(8) {
+            return Err(Error::InvalidRing(ring));
+        }
+        if rank == 0 {
+            return Err(Error::EmptyInput);
+        }
+        if !(1..=32).contains(&eta) {
+            return Err(Error::InvalidNoise(eta));
+        }
+
+        Ok(Parameters { ring, rank, eta })
+    }
+
+    /// The degree `n` of the ring.
+    pub fn ring(&self) -> usize {
+        self.ring
+    }
+
+    /// The rank of the module, 1 for ring-LWE.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The width of the noise distribution.
+    pub fn eta(&self) -> u32 {
+        self.eta
+    }
+
+    /// The length in bytes of the messages, one bit per coefficient.
+    pub fn message_length(&self) -> usize {
+        self.ring / 8
+    }
+
+    /// Sample a vector of `rank` noise polynomials.
+    fn noise_vector<R: Rng + ?Sized>(&self, rand: &mut R) -> Matrix<Polynomial<Coefficient>> {
+        let content = (0..self.rank)
+            .map(|_| sample_noise(self.ring, self.eta, rand))
+            .collect();
+
+        Matrix::new(content, 1, self.rank)
+    }
+}
+
+/// The public key `(A, t = A s + e)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    parameters: Parameters,
+    a: Matrix<Polynomial<Coefficient>>,
+    t: Matrix<Polynomial<Coefficient>>,
+}
+
+impl PublicKey {
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+}
+
+/// The secret key `s`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretKey {
+    parameters: Parameters,
+    s: Matrix<Polynomial<Coefficient>>,
+}
+
+impl SecretKey {
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+}
+
+/// An encrypted message `(u, v)`, with the parameters of the key it was encrypted for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ciphertext {
+    parameters: Parameters,
+    u: Matrix<Polynomial<Coefficient>>,
+    v: Polynomial<Coefficient>,
+}
+
+impl Ciphertext {
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+}
+
+/// Sample a polynomial of `R_q` whose coefficients follow the centered binomial
+/// distribution of width `eta`: the difference between the numbers of heads in two series
+/// of `eta` coin flips, in `[-eta, eta]`.
+pub fn sample_noise<R: Rng + ?Sized>(
+    ring: usize,
+    eta: u32,
+    rand: &mut R,
+) -> Polynomial<Coefficient> {
+    let mask = u32::MAX >> (32 - eta.clamp(1, 32));
+    let mut flips = || (rand.gen::<u32>() & mask).count_ones() as i64;
+
+    Polynomial::new((0..ring).map(|_| Zq::new(flips() - flips())).collect())
+}
+
+/// Generate a key pair.
+pub fn keygen<R: Rng + ?Sized>(parameters: Parameters, rand: &mut R) -> (PublicKey, SecretKey) {
+    let Parameters { ring, rank, .. } = parameters;
+
+    let a = Matrix::new(
+        (0..rank * rank)
+            .map(|_| Polynomial::new((0..ring).map(|_| Zq::random(rand)).collect()))
+            .collect(),
+        rank,
+        rank,
+    );
+    let s = parameters.noise_vector(rand);
+    let e = parameters.noise_vector(rand);
+    let t = Matrix::add(&Matrix::mul_in_negacyclic_ring(&a, &s, ring), &e);
+
+    (PublicKey { parameters, a, t }, SecretKey { parameters, s })
+}
+
+/// Encrypt a message of [`Parameters::message_length`] bytes.
+///
+/// Panics if the message doesn't have that length, see [`try_encrypt`].
+pub fn encrypt<R: Rng + ?Sized>(
+    public_key: &PublicKey,
+    message: &[u8],
+    rand: &mut R,
+) -> Ciphertext {
+    try_encrypt(public_key, message, rand).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Encrypt a message, or returns an error if it isn't [`Parameters::message_length`] bytes
+/// long. Its bit `i` is the bit `i % 8` of its byte `i / 8`.
+pub fn try_encrypt<R: Rng + ?Sized>(
+    public_key: &PublicKey,
+    message: &[u8],
+    rand: &mut R,
+) -> Result<Ciphertext, Error> {
+    let parameters = public_key.parameters;
+    if message.len() != parameters.message_length() {
+        return Err(Error::LengthMismatch {
+            expected: parameters.message_length(),
+            found: message.len(),
+        });
+    }
+
+    let ring = parameters.ring;
+    let r = parameters.noise_vector(rand);
+    let e1 = parameters.noise_vector(rand);
+    let e2 = sample_noise(ring, parameters.eta, rand);
+
+    // The bits are encoded as 0 or ⌈q/2⌋, as far apart as possible.
+    let half = Zq::new(MODULUS.div_ceil(2) as i64);
+    let encoded = Polynomial::new(
+        (0..ring)
+            .map(|i| match message[i / 8] >> (i % 8) & 1 {
+                1 => half,
+                _ => Zq::ZERO,
+            })
+            .collect(),
+    );
+
+    let u = Matrix::add(
+        &Matrix::mul_in_negacyclic_ring(&public_key.a.transpose(), &r, ring),
+        &e1,
+    );
+    let tr = &Matrix::mul_in_negacyclic_ring(&public_key.t.transpose(), &r, ring)[(0, 0)];
+    let v = Polynomial::add(&Polynomial::add(tr, &e2), &encoded);
+
+    Ok(Ciphertext { parameters, u, v })
+}
+
+/// Decrypt a ciphertext.
+///
+/// Panics if it was encrypted for other parameters, see [`try_decrypt`].
+pub fn decrypt(secret_key: &SecretKey, ciphertext: &Ciphertext) -> Vec<u8> {
+    try_decrypt(secret_key, ciphertext).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Decrypt a ciphertext, or returns an error if it was encrypted for other parameters. A
+/// ciphertext encrypted for another key with the same parameters just decrypts to garbage.
+pub fn try_decrypt(secret_key: &SecretKey, ciphertext: &Ciphertext) -> Result<Vec<u8>, Error> {
+    if ciphertext.parameters != secret_key.parameters {
+        return Err(Error::ParameterMismatch);
+    }
+
+    let ring = secret_key.parameters.ring;
+    let su = &Matrix::try_mul_in_negacyclic_ring(&secret_key.s.transpose(), &ciphertext.u, ring)?
+        [(0, 0)];
+    let noisy = Polynomial::sub(&ciphertext.v, su).reduce_negacyclic_to(ring);
+
+    // Coefficients closer to ⌈q/2⌋ than to 0 are ones.
+    let mut message = vec![0; secret_key.parameters.message_length()];
+    for (i, c) in noisy.coefficients.iter().enumerate().take(ring) {
+        if c.centered().unsigned_abs() > MODULUS / 4 {
+            message[i / 8] |= 1 << (i % 8);
+        }
+    }
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random;
+
+    #[test]
+    fn test_noise() {
+        let mut rand = random::seeded(49);
+        let noise = sample_noise(1024, 2, &mut rand);
+
+        assert_eq!(1023, noise.degree);
+        assert!(noise.coefficients.iter().all(|c| c.centered().abs() <= 2));
+        // The distribution is centered and has a variance of eta / 2.
+        let sum: i64 = noise.coefficients.iter().map(|c| c.centered() as i64).sum();
+        let squares: i64 = noise
+            .coefficients
+            .iter()
+            .map(|c| (c.centered() as i64).pow(2))
+            .sum();
+        assert!(sum.abs() < 100);
+        assert!((900..1150).contains(&squares));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let mut rand = random::seeded(49);
+
+        for parameters in [
+            Parameters::TOY,
+            Parameters::RLWE_256,
+            Parameters::MLWE_512,
+            Parameters::MLWE_768,
+            Parameters::MLWE_1024,
+            Parameters::new(16, 3, 1),
+        ] {
+            let (public_key, secret_key) = keygen(parameters, &mut rand);
+            let message: Vec<u8> = (0..parameters.message_length())
+                .map(|_| rand.gen())
+                .collect();
+
+            let ciphertext = encrypt(&public_key, &message, &mut rand);
+            assert_eq!(message, decrypt(&secret_key, &ciphertext));
+
+            // Encryption is randomized, and another key doesn't decrypt.
+            assert_ne!(ciphertext, encrypt(&public_key, &message, &mut rand));
+            let (_, other_key) = keygen(parameters, &mut rand);
+            assert_ne!(message, decrypt(&other_key, &ciphertext));
+        }
+    }
+
+    #[test]
+    fn test_known_answers() {
+        // The same seed always gives the same keys and ciphertexts.
+        let mut rand = random::seeded(2024);
+        let (public_key, secret_key) = keygen(Parameters::TOY, &mut rand);
+        let message = *b"KAT test";
+        let ciphertext = encrypt(&public_key, &message, &mut rand);
+
+        let head = |p: &Polynomial<Coefficient>| -> Vec<u32> {
+            p.coefficients[..4].iter().map(Coefficient::value).collect()
+        };
+        assert_eq!(vec![848, 1704, 3282, 2434], head(&public_key.t[(0, 0)]));
+        assert_eq!(vec![1, 1, 1, 3328], head(&secret_key.s[(0, 0)]));
+        assert_eq!(vec![1130, 2604, 641, 120], head(&ciphertext.u[(0, 0)]));
+        assert_eq!(vec![1515, 1926, 1055, 2826], head(&ciphertext.v));
+        assert_eq!(message.to_vec(), decrypt(&secret_key, &ciphertext));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Err(Error::InvalidRing(12)), Parameters::try_new(12, 2, 2));
+        assert_eq!(Err(Error::EmptyInput), Parameters::try_new(16, 0, 2));
+        assert_eq!(Err(Error::InvalidNoise(0)), Parameters::try_new(16, 1, 0));
+
+        let mut rand = random::seeded(49);
+        let (public_key, _) = keygen(Parameters::TOY, &mut rand);
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 8,
+                found: 3
+            }),
+            try_encrypt(&public_key, b"abc", &mut rand)
+        );
+
+        let (public_key, _) = keygen(Parameters::MLWE_512, &mut rand);
+        let (_, secret_key) = keygen(Parameters::MLWE_768, &mut rand);
+        let ciphertext = encrypt(&public_key, &[0; 32], &mut rand);
+        assert_eq!(
+            Err(Error::ParameterMismatch),
+            try_decrypt(&secret_key, &ciphertext)
+        );
+
+        // The same rank but another ring, or another noise width, is caught as well.
+        let (public_key, _) = keygen(Parameters::new(16, 1, 2), &mut rand);
+        let (_, secret_key) = keygen(Parameters::new(32, 1, 2), &mut rand);
+        let ciphertext = encrypt(&public_key, &[0; 2], &mut rand);
+        assert_eq!(
+            Err(Error::ParameterMismatch),
+            try_decrypt(&secret_key, &ciphertext)
+        );
+        let (_, secret_key) = keygen(Parameters::new(16, 1, 3), &mut rand);
+        assert_eq!(
+            Err(Error::ParameterMismatch),
+            try_decrypt(&secret_key, &ciphertext)
+        );
+    }
+}
@@ -1,153 +1,1218 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut, Range},
+};
 
-use rand::rngs::ThreadRng;
+use rand::Rng;
 
-use crate::polynomial::Polynomial;
+use crate::{
+    complex::Complex,
+    element::{Conjugate, Element, Field},
+    error::Error,
+    polynomial::Polynomial,
+    random::RandomMatrixConfig,
+};
 
+/// A row-major matrix, holding polynomials by default.
+///
+/// Any [`Element`] can be stored, such as [`crate::complex::Complex`] numbers for numeric
+/// linear algebra.
 #[repr(C)]
 #[derive(Debug, PartialEq, Clone)]
-pub struct Matrix {
+pub struct Matrix<E = Polynomial> {
     pub width: usize,
     pub height: usize,
-    pub content: Vec<Polynomial>,
+    pub content: Vec<E>,
 }
 
-impl Matrix {
-    pub fn new(content: Vec<Polynomial>, width: usize, height: usize) -> Matrix {
-        assert!(content.len() % width == 0);
-        assert!(content.len() / width == height);
+impl<E> Matrix<E> {
+    /// Create a matrix from its row-major `content`.
+    ///
+    /// Panics if `content` doesn't hold exactly `width * height` elements, see [`Matrix::try_new`].
+    pub fn new(content: Vec<E>, width: usize, height: usize) -> Matrix<E> {
+        Self::try_new(content, width, height).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        Matrix {
+    /// Create a matrix from its row-major `content`, which must hold exactly `width * height`
+    /// elements. Both dimensions must be non-zero.
+    pub fn try_new(content: Vec<E>, width: usize, height: usize) -> Result<Matrix<E>, Error> {
+        if width == 0 || height == 0 {
+            return Err(Error::EmptyInput);
+        }
+
+        if content.len() != width * height {
+            return Err(Error::LengthMismatch {
+                expected: width * height,
+                found: content.len(),
+            });
+        }
+
+        Ok(Matrix {
             width,
             height,
             content,
+        })
+    }
+
+    /// Returns the matrix of the same size with `f` applied to every element, to convert
+    /// between element types.
+    pub fn map<F>(&self, f: impl FnMut(&E) -> F) -> Matrix<F> {
+        Matrix {
+            width: self.width,
+            height: self.height,
+            content: self.content.iter().map(f).collect(),
         }
     }
 
-    /// Add two matrices. The sizes of `a` and `b` must match.
-    pub fn add(a: &Matrix, b: &Matrix) -> Matrix {
-        assert_eq!(a.width, b.width);
-        assert_eq!(a.height, b.height);
+    fn check_same_size<F>(a: &Matrix<E>, b: &Matrix<F>) -> Result<(), Error> {
+        if a.width != b.width || a.height != b.height {
+            return Err(Error::DimensionMismatch {
+                left: (a.width, a.height),
+                right: (b.width, b.height),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_mul_size<F>(a: &Matrix<E>, b: &Matrix<F>) -> Result<(), Error> {
+        if a.width != b.height {
+            return Err(Error::DimensionMismatch {
+                left: (a.width, a.height),
+                right: (b.width, b.height),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the element at (`row`, `column`), or `None` if it's out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Option<&E> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+
+        self.content.get(column + row * self.width)
+    }
+
+    /// Returns a mutable reference to the element at (`row`, `column`), or `None` if it's out
+    /// of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut E> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+
+        self.content.get_mut(column + row * self.width)
+    }
+
+    /// Returns an iterator over the rows of the matrix, as slices.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[E]> + '_ {
+        self.content.chunks(self.width)
+    }
+
+    /// Returns an iterator over the columns of the matrix, each one being an iterator over
+    /// its elements from top to bottom.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl Iterator<Item = &E> + '_> + '_ {
+        (0..self.width).map(move |j| self.content[j..].iter().step_by(self.width))
+    }
+
+    /// Change the shape of the matrix, keeping its elements in the same row-major order.
+    ///
+    /// Panics if `width * height` isn't the number of elements, see [`Matrix::try_reshape`].
+    pub fn reshape(self, width: usize, height: usize) -> Matrix<E> {
+        self.try_reshape(width, height)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Change the shape of the matrix, keeping its elements in the same row-major order,
+    /// returning an error if `width * height` isn't the number of elements.
+    pub fn try_reshape(self, width: usize, height: usize) -> Result<Matrix<E>, Error> {
+        Matrix::try_new(self.content, width, height)
+    }
+
+    fn out_of_bounds(&self, row: usize, column: usize) -> Error {
+        Error::OutOfBounds {
+            index: (row, column),
+            size: (self.width, self.height),
+        }
+    }
+}
+
+impl<E> Index<(usize, usize)> for Matrix<E> {
+    type Output = E;
+
+    /// Returns the element at (`row`, `column`).
+    ///
+    /// Panics if it's out of bounds, see [`Matrix::get`].
+    fn index(&self, (row, column): (usize, usize)) -> &E {
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("{}", self.out_of_bounds(row, column)))
+    }
+}
+
+impl<E> IndexMut<(usize, usize)> for Matrix<E> {
+    /// Returns a mutable reference to the element at (`row`, `column`).
+    ///
+    /// Panics if it's out of bounds, see [`Matrix::get_mut`].
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut E {
+        let error = self.out_of_bounds(row, column);
+
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("{error}"))
+    }
+}
+
+impl<E: Clone> Matrix<E> {
+    /// Concatenate matrices of the same height side by side.
+    ///
+    /// Panics if `blocks` is empty or if their heights differ, see [`Matrix::try_hstack`].
+    pub fn hstack(blocks: &[&Matrix<E>]) -> Matrix<E> {
+        Self::try_hstack(blocks).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        let mut result = vec![Polynomial::zero(); b.width * a.height];
+    /// Concatenate matrices of the same height side by side, returning an error if `blocks`
+    /// is empty or if their heights differ.
+    pub fn try_hstack(blocks: &[&Matrix<E>]) -> Result<Matrix<E>, Error> {
+        let first = blocks.first().ok_or(Error::EmptyInput)?;
 
-        for i in 0..a.height {
-            for j in 0..b.width {
-                let idx = j + i * b.width;
+        if let Some(block) = blocks.iter().find(|block| block.height != first.height) {
+            return Err(Error::DimensionMismatch {
+                left: (first.width, first.height),
+                right: (block.width, block.height),
+            });
+        }
+
+        let width = blocks.iter().map(|block| block.width).sum();
+        let mut content = Vec::with_capacity(width * first.height);
 
-                result[idx] = Polynomial::add(&a.content[idx], &b.content[idx]);
+        for i in 0..first.height {
+            for block in blocks {
+                content.extend_from_slice(&block.content[i * block.width..(i + 1) * block.width]);
+            }
+        }
+
+        Ok(Matrix {
+            width,
+            height: first.height,
+            content,
+        })
+    }
+
+    /// Concatenate matrices of the same width on top of each other.
+    ///
+    /// Panics if `blocks` is empty or if their widths differ, see [`Matrix::try_vstack`].
+    pub fn vstack(blocks: &[&Matrix<E>]) -> Matrix<E> {
+        Self::try_vstack(blocks).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Concatenate matrices of the same width on top of each other, returning an error if
+    /// `blocks` is empty or if their widths differ.
+    pub fn try_vstack(blocks: &[&Matrix<E>]) -> Result<Matrix<E>, Error> {
+        let first = blocks.first().ok_or(Error::EmptyInput)?;
+
+        if let Some(block) = blocks.iter().find(|block| block.width != first.width) {
+            return Err(Error::DimensionMismatch {
+                left: (first.width, first.height),
+                right: (block.width, block.height),
+            });
+        }
+
+        let content: Vec<E> = blocks
+            .iter()
+            .flat_map(|block| block.content.iter().cloned())
+            .collect();
+
+        Ok(Matrix {
+            width: first.width,
+            height: content.len() / first.width,
+            content,
+        })
+    }
+
+    /// Assemble a block matrix from rows of blocks: the blocks of a row must have the same
+    /// height, and the rows the same total width.
+    ///
+    /// Panics if they don't or if there are no blocks, see [`Matrix::try_block`].
+    pub fn block(rows: &[&[&Matrix<E>]]) -> Matrix<E> {
+        Self::try_block(rows).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Assemble a block matrix from rows of blocks, returning an error if the blocks of a row
+    /// don't have the same height, if the rows don't have the same total width, or if there
+    /// are no blocks.
+    pub fn try_block(rows: &[&[&Matrix<E>]]) -> Result<Matrix<E>, Error> {
+        let rows = rows
+            .iter()
+            .map(|row| Self::try_hstack(row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_vstack(&rows.iter().collect::<Vec<_>>())
+    }
+}
+
+/// Side of the square tiles transpositions work on, so that both the rows being read and the
+/// rows being written stay in cache.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Side of the square tiles of output elements computed together by the matrix products.
+const MUL_BLOCK: usize = 16;
+
+/// Number of element multiplications below which a matrix product stays on one thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// A reasonable crossover for [`Matrix::mul_strassen`]: below it, the extra additions of
+/// Strassen's algorithm cost more than the multiplications they save. The higher the degrees
+/// of the polynomials, the more multiplications dominate and the more Strassen pays off.
+pub const STRASSEN_CROSSOVER: usize = 64;
+
+impl<E: Clone> Matrix<E> {
+    /// Returns the transpose of the matrix, copied one square tile at a time.
+    pub fn transpose(&self) -> Matrix<E> {
+        let mut slots: Vec<Option<E>> = vec![None; self.content.len()];
+
+        for i0 in (0..self.height).step_by(TRANSPOSE_BLOCK) {
+            for j0 in (0..self.width).step_by(TRANSPOSE_BLOCK) {
+                for i in i0..(i0 + TRANSPOSE_BLOCK).min(self.height) {
+                    for j in j0..(j0 + TRANSPOSE_BLOCK).min(self.width) {
+                        slots[i + j * self.height] = Some(self.content[j + i * self.width].clone());
+                    }
+                }
             }
         }
 
         Matrix {
-            width: b.width,
+            width: self.height,
+            height: self.width,
+            content: slots
+                .into_iter()
+                .map(|e| e.expect("every element is visited by a tile"))
+                .collect(),
+        }
+    }
+}
+
+impl<E> Matrix<E> {
+    /// Transpose the matrix without allocating a second one.
+    ///
+    /// Square matrices swap mirrored tiles. Other matrices follow the cycles of the
+    /// transposition permutation, tracking visited positions in a bitset.
+    pub fn transpose_in_place(&mut self) {
+        if self.width == self.height {
+            self.transpose_square_in_place();
+        } else {
+            self.transpose_cycles_in_place();
+        }
+
+        std::mem::swap(&mut self.width, &mut self.height);
+    }
+
+    fn transpose_square_in_place(&mut self) {
+        let n = self.width;
+
+        for i0 in (0..n).step_by(TRANSPOSE_BLOCK) {
+            for j0 in (i0..n).step_by(TRANSPOSE_BLOCK) {
+                for i in i0..(i0 + TRANSPOSE_BLOCK).min(n) {
+                    // On diagonal tiles, only swap the elements above the diagonal.
+                    let start = if i0 == j0 { i + 1 } else { j0 };
+
+                    for j in start..(j0 + TRANSPOSE_BLOCK).min(n) {
+                        self.content.swap(j + i * n, i + j * n);
+                    }
+                }
+            }
+        }
+    }
+
+    fn transpose_cycles_in_place(&mut self) {
+        let len = self.content.len();
+        if len < 3 {
+            return;
+        }
+
+        // The element at `i = row * width + column` moves to `column * height + row`, which
+        // is `i * height mod (len - 1)`. The first and last elements never move.
+        let destination = |i: usize| (i * self.height) % (len - 1);
+        let mut visited = vec![0u64; len.div_ceil(64)];
+
+        for start in 1..len - 1 {
+            if visited[start / 64] & (1 << (start % 64)) != 0 {
+                continue;
+            }
+
+            // Swapping through `start` rotates the cycle: each swap drops the element held
+            // at `start` to its destination, and picks up the one that was there.
+            let mut i = destination(start);
+            while i != start {
+                self.content.swap(start, i);
+                visited[i / 64] |= 1 << (i % 64);
+                i = destination(i);
+            }
+            visited[start / 64] |= 1 << (start % 64);
+        }
+    }
+}
+
+impl<E: Element + Conjugate> Matrix<E> {
+    /// Returns the conjugate transpose (or Hermitian transpose) of the matrix, conjugating
+    /// every element. For polynomials, every coefficient is conjugated.
+    pub fn conjugate_transpose(&self) -> Matrix<E> {
+        let mut result = self.transpose();
+
+        result.content.iter_mut().for_each(|e| {
+            *e = e.conj();
+        });
+
+        result
+    }
+}
+
+impl<E: Element> Matrix<E> {
+    /// Combine two matrices of the same size element by element.
+    fn try_zip_with<F>(a: &Matrix<E>, b: &Matrix<E>, mut f: F) -> Result<Matrix<E>, Error>
+    where
+        F: FnMut(&E, &E) -> Result<E, Error>,
+    {
+        Self::check_same_size(a, b)?;
+
+        let content = a
+            .content
+            .iter()
+            .zip(&b.content)
+            .map(|(x, y)| f(x, y))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Matrix {
+            width: a.width,
             height: a.height,
-            content: result,
+            content,
+        })
+    }
+
+    /// Multiply two matrices, accumulating every product into its output element with
+    /// `mul_add` and normalizing the finished elements with `finish`.
+    fn try_product_with<M, F>(
+        a: &Matrix<E>,
+        b: &Matrix<E>,
+        mul_add: M,
+        finish: F,
+    ) -> Result<Matrix<E>, Error>
+    where
+        M: Fn(&mut E, &E, &E) + Sync,
+        F: Fn(E) -> E + Sync,
+    {
+        Self::check_mul_size(a, b)?;
+
+        Self::try_product_transposed_with(a, &b.transpose(), mul_add, finish)
+    }
+
+    /// Multiply `a` by the transpose of `bt`, accumulating every product into its output
+    /// element with `mul_add` and normalizing the finished elements with `finish`. Both
+    /// operands are walked along their rows, which are contiguous.
+    ///
+    /// With the `parallel` feature, large products are split into bands of output rows
+    /// computed on separate threads.
+    fn try_product_transposed_with<M, F>(
+        a: &Matrix<E>,
+        bt: &Matrix<E>,
+        mul_add: M,
+        finish: F,
+    ) -> Result<Matrix<E>, Error>
+    where
+        M: Fn(&mut E, &E, &E) + Sync,
+        F: Fn(E) -> E + Sync,
+    {
+        if a.width != bt.width {
+            return Err(Error::DimensionMismatch {
+                left: (a.width, a.height),
+                right: (bt.height, bt.width),
+            });
         }
+
+        #[cfg(feature = "parallel")]
+        let content = Self::product_rows_parallel(a, bt, &mul_add, &finish);
+        #[cfg(not(feature = "parallel"))]
+        let content = Self::product_rows(a, bt, 0..a.height, &mul_add, &finish);
+
+        Ok(Matrix {
+            width: bt.height,
+            height: a.height,
+            content,
+        })
+    }
+
+    /// Compute the `rows` of the product of `a` by the transpose of `bt`.
+    ///
+    /// Output rows are computed by bands of [`MUL_BLOCK`] rows, themselves split into
+    /// square tiles, and the shared dimension is walked by blocks of [`MUL_BLOCK`] too, so
+    /// that the rows of `a` and `bt` used by a tile stay in cache. Products are accumulated
+    /// in place into the output elements, without intermediate allocations.
+    fn product_rows<M, F>(
+        a: &Matrix<E>,
+        bt: &Matrix<E>,
+        rows: Range<usize>,
+        mul_add: &M,
+        finish: &F,
+    ) -> Vec<E>
+    where
+        M: Fn(&mut E, &E, &E),
+        F: Fn(E) -> E,
+    {
+        let (width, depth) = (bt.height, a.width);
+        let mut result = Vec::with_capacity(rows.len() * width);
+
+        for band_start in rows.clone().step_by(MUL_BLOCK) {
+            let band_end = (band_start + MUL_BLOCK).min(rows.end);
+            let mut band = vec![E::zero(); (band_end - band_start) * width];
+
+            for j_start in (0..width).step_by(MUL_BLOCK) {
+                let j_end = (j_start + MUL_BLOCK).min(width);
+
+                for k_start in (0..depth).step_by(MUL_BLOCK) {
+                    let k_end = (k_start + MUL_BLOCK).min(depth);
+
+                    for i in band_start..band_end {
+                        let a_row = &a.content[k_start + i * depth..k_end + i * depth];
+                        let band_row = &mut band[(i - band_start) * width..][j_start..j_end];
+                        let bt_rows = bt.content.chunks(depth).skip(j_start);
+
+                        for (acc, bt_row) in band_row.iter_mut().zip(bt_rows) {
+                            for (x, y) in a_row.iter().zip(&bt_row[k_start..k_end]) {
+                                mul_add(acc, x, y);
+                            }
+                        }
+                    }
+                }
+            }
+
+            result.extend(band.into_iter().map(finish));
+        }
+
+        result
+    }
+
+    /// Compute the product of `a` by the transpose of `bt` with [`Matrix::product_rows`],
+    /// splitting the output rows between the available threads when the product is large
+    /// enough to be worth it.
+    #[cfg(feature = "parallel")]
+    fn product_rows_parallel<M, F>(a: &Matrix<E>, bt: &Matrix<E>, mul_add: &M, finish: &F) -> Vec<E>
+    where
+        M: Fn(&mut E, &E, &E) + Sync,
+        F: Fn(E) -> E + Sync,
+    {
+        // Querying the parallelism reads the cgroup limits on Linux, which costs more than a
+        // small product: check the size first.
+        if a.height * a.width * bt.height < PARALLEL_THRESHOLD {
+            return Self::product_rows(a, bt, 0..a.height, mul_add, finish);
+        }
+
+        let bands = a.height.div_ceil(MUL_BLOCK);
+        let threads = std::thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(bands);
+
+        if threads <= 1 {
+            return Self::product_rows(a, bt, 0..a.height, mul_add, finish);
+        }
+
+        // Whole bands per thread, so that every thread works on full tiles.
+        let rows_per_thread = bands.div_ceil(threads) * MUL_BLOCK;
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..a.height)
+                .step_by(rows_per_thread)
+                .map(|start| {
+                    let rows = start..(start + rows_per_thread).min(a.height);
+                    scope.spawn(move || Self::product_rows(a, bt, rows, mul_add, finish))
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("a matrix product thread panicked"))
+                .collect()
+        })
+    }
+
+    /// Returns the `width`x`height` matrix full of zeros.
+    ///
+    /// Panics if a dimension is 0.
+    pub fn zeros(width: usize, height: usize) -> Matrix<E> {
+        Matrix::new(vec![E::zero(); width * height], width, height)
+    }
+
+    /// Returns the `n`x`n` identity matrix.
+    pub fn identity(n: usize) -> Matrix<E> {
+        let content = (0..n * n)
+            .map(|idx| {
+                if idx % (n + 1) == 0 {
+                    E::one()
+                } else {
+                    E::zero()
+                }
+            })
+            .collect();
+
+        Matrix::new(content, n, n)
+    }
+
+    /// Returns the sum of the diagonal elements.
+    pub fn trace(&self) -> E {
+        self.content
+            .iter()
+            .step_by(self.width + 1)
+            .take(self.height)
+            .fold(E::zero(), |sum, e| E::add(&sum, e))
+    }
+
+    /// Add two matrices. The sizes of `a` and `b` must match.
+    ///
+    /// Panics if they don't, see [`Matrix::try_add`].
+    pub fn add(a: &Matrix<E>, b: &Matrix<E>) -> Matrix<E> {
+        Self::try_add(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two matrices, returning an error if the sizes of `a` and `b` don't match.
+    pub fn try_add(a: &Matrix<E>, b: &Matrix<E>) -> Result<Matrix<E>, Error> {
+        Self::try_zip_with(a, b, |x, y| Ok(E::add(x, y)))
     }
 
     /// Multiply two matrices. The width of `a` must match the height of `b`.
-    pub fn mul(a: &Matrix, b: &Matrix) -> Matrix {
-        assert_eq!(a.width, b.height);
+    ///
+    /// Panics if it doesn't, see [`Matrix::try_mul`].
+    pub fn mul(a: &Matrix<E>, b: &Matrix<E>) -> Matrix<E> {
+        Self::try_mul(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        let mut result = vec![Polynomial::zero(); b.width * a.height];
+    /// Multiply two matrices, returning an error if the width of `a` doesn't match the
+    /// height of `b`.
+    pub fn try_mul(a: &Matrix<E>, b: &Matrix<E>) -> Result<Matrix<E>, Error> {
+        Self::try_product_with(a, b, E::mul_add, E::normalized)
+    }
 
-        for i in 0..a.height {
-            for j in 0..b.width {
-                for k in 0..a.width {
-                    let mul =
-                        Polynomial::mul(&a.content[k + i * a.width], &b.content[j + k * b.width]);
-                    let add = Polynomial::add(&result[j + i * b.width], &mul);
+    /// Multiply `a` by the transpose of `bt`, which is how [`Matrix::mul`] walks its right
+    /// operand. Use it to skip the transposition when the right operand is already
+    /// available transposed. The widths of `a` and `bt` must match.
+    ///
+    /// Panics if they don't, see [`Matrix::try_mul_transposed`].
+    pub fn mul_transposed(a: &Matrix<E>, bt: &Matrix<E>) -> Matrix<E> {
+        Self::try_mul_transposed(a, bt).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-                    result[j + i * b.width] = add;
+    /// Multiply `a` by the transpose of `bt`, returning an error if the widths of `a` and
+    /// `bt` don't match.
+    pub fn try_mul_transposed(a: &Matrix<E>, bt: &Matrix<E>) -> Result<Matrix<E>, Error> {
+        Self::try_product_transposed_with(a, bt, E::mul_add, E::normalized)
+    }
+
+    /// Multiply two matrices of the same size element by element.
+    ///
+    /// Panics if their sizes don't match, see [`Matrix::try_hadamard`].
+    pub fn hadamard(a: &Matrix<E>, b: &Matrix<E>) -> Matrix<E> {
+        Self::try_hadamard(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices element by element, returning an error if the sizes of `a` and
+    /// `b` don't match.
+    pub fn try_hadamard(a: &Matrix<E>, b: &Matrix<E>) -> Result<Matrix<E>, Error> {
+        Self::try_zip_with(a, b, |x, y| Ok(E::mul(x, y)))
+    }
+
+    /// Returns the Kronecker product of `a` and `b`: the block matrix whose block at
+    /// (`i`, `j`) is `b` multiplied by the element of `a` at (`i`, `j`).
+    pub fn kronecker(a: &Matrix<E>, b: &Matrix<E>) -> Matrix<E> {
+        let width = a.width * b.width;
+        let mut content = Vec::with_capacity(width * a.height * b.height);
+
+        for a_row in a.rows() {
+            for b_row in b.rows() {
+                for x in a_row {
+                    content.extend(b_row.iter().map(|y| E::mul(x, y)));
                 }
             }
         }
 
         Matrix {
-            width: b.width,
-            height: a.height,
-            content: result,
+            width,
+            height: a.height * b.height,
+            content,
         }
     }
+}
 
-    /// Add two matrices while restricting the contained polynomials to the provided
-    /// ring degree. `a` and `b` must have the same size.
-    pub fn add_in_ring(a: &Matrix, b: &Matrix, ring: usize) -> Matrix {
-        assert_eq!(a.width, b.width);
-        assert_eq!(a.height, b.height);
+impl<E: Element> Matrix<E> {
+    /// Multiply two matrices with Strassen's algorithm, which trades one of the eight block
+    /// multiplications of each recursion level for extra additions. The width of `a` must
+    /// match the height of `b`.
+    ///
+    /// Blocks of at most `crossover` rows are multiplied with the classic kernel, see
+    /// [`STRASSEN_CROSSOVER`] for a default.
+    ///
+    /// Panics if the sizes don't match, see [`Matrix::try_mul_strassen`].
+    pub fn mul_strassen(a: &Matrix<E>, b: &Matrix<E>, crossover: usize) -> Matrix<E> {
+        Self::try_mul_strassen(a, b, crossover).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices with Strassen's algorithm, returning an error if the width of
+    /// `a` doesn't match the height of `b`.
+    pub fn try_mul_strassen(
+        a: &Matrix<E>,
+        b: &Matrix<E>,
+        crossover: usize,
+    ) -> Result<Matrix<E>, Error> {
+        Self::check_mul_size(a, b)?;
 
-        let mut result = vec![Polynomial::zero(); b.width * a.height];
+        Ok(Self::strassen_padded(a, b, crossover, &|x, y| {
+            Self::mul(x, y)
+        }))
+    }
 
-        for i in 0..a.height {
-            for j in 0..b.width {
-                let idx = j + i * b.width;
+    /// Pad `a` and `b` with zeros to a square size that can be halved down to at most
+    /// `crossover`, multiply them with [`Matrix::strassen`] and strip the padding.
+    ///
+    /// The padded size is the smallest `m * 2^k` covering both operands with `m <= crossover`,
+    /// rather than the next power of two, to keep the padding small.
+    fn strassen_padded<P>(a: &Matrix<E>, b: &Matrix<E>, crossover: usize, base: &P) -> Matrix<E>
+    where
+        P: Fn(&Matrix<E>, &Matrix<E>) -> Matrix<E>,
+    {
+        let crossover = crossover.max(1);
+        let size = a.height.max(a.width).max(b.width);
 
-                result[idx] = Polynomial::add_in_ring(&a.content[idx], &b.content[idx], ring);
-            }
+        let mut levels = 0;
+        while size.div_ceil(1 << levels) > crossover {
+            levels += 1;
         }
+        let n = size.div_ceil(1 << levels) << levels;
+
+        let pad = |m: &Matrix<E>| {
+            let mut content = vec![E::zero(); n * n];
+            for (i, row) in m.content.chunks(m.width).enumerate() {
+                content[i * n..i * n + m.width].clone_from_slice(row);
+            }
+            content
+        };
+
+        let product = Self::strassen(pad(a), pad(b), n, crossover, base);
+        let content = product
+            .chunks(n)
+            .take(a.height)
+            .flat_map(|row| row[..b.width].to_vec())
+            .collect();
 
         Matrix {
             width: b.width,
             height: a.height,
-            content: result,
+            content,
+        }
+    }
+
+    /// Multiply the square `n`x`n` row-major matrices `a` and `b` recursively, using `base`
+    /// once they're at most `crossover` wide or can't be halved anymore.
+    fn strassen<P>(a: Vec<E>, b: Vec<E>, n: usize, crossover: usize, base: &P) -> Vec<E>
+    where
+        P: Fn(&Matrix<E>, &Matrix<E>) -> Matrix<E>,
+    {
+        if n <= crossover || n % 2 == 1 {
+            let a = Matrix {
+                width: n,
+                height: n,
+                content: a,
+            };
+            let b = Matrix {
+                width: n,
+                height: n,
+                content: b,
+            };
+
+            return base(&a, &b).content;
+        }
+
+        let h = n / 2;
+        let [a11, a12, a21, a22] = Self::quadrants(&a, n);
+        let [b11, b12, b21, b22] = Self::quadrants(&b, n);
+
+        let add =
+            |x: &[E], y: &[E]| -> Vec<E> { x.iter().zip(y).map(|(x, y)| E::add(x, y)).collect() };
+        let sub =
+            |x: &[E], y: &[E]| -> Vec<E> { x.iter().zip(y).map(|(x, y)| E::sub(x, y)).collect() };
+        let mul = |x: Vec<E>, y: Vec<E>| Self::strassen(x, y, h, crossover, base);
+
+        let m1 = mul(add(&a11, &a22), add(&b11, &b22));
+        let m2 = mul(add(&a21, &a22), b11.clone());
+        let m3 = mul(a11.clone(), sub(&b12, &b22));
+        let m4 = mul(a22.clone(), sub(&b21, &b11));
+        let m5 = mul(add(&a11, &a12), b22.clone());
+        let m6 = mul(sub(&a21, &a11), add(&b11, &b12));
+        let m7 = mul(sub(&a12, &a22), add(&b21, &b22));
+
+        let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+        let c12 = add(&m3, &m5);
+        let c21 = add(&m2, &m4);
+        let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+        let mut result = Vec::with_capacity(n * n);
+        for i in 0..h {
+            result.extend_from_slice(&c11[i * h..(i + 1) * h]);
+            result.extend_from_slice(&c12[i * h..(i + 1) * h]);
+        }
+        for i in 0..h {
+            result.extend_from_slice(&c21[i * h..(i + 1) * h]);
+            result.extend_from_slice(&c22[i * h..(i + 1) * h]);
         }
+
+        result
+    }
+
+    /// Split a square `n`x`n` row-major matrix, `n` being even, into its four quadrants.
+    fn quadrants(m: &[E], n: usize) -> [Vec<E>; 4] {
+        let h = n / 2;
+        let quadrant = |rows: Range<usize>, columns: Range<usize>| -> Vec<E> {
+            rows.flat_map(|i| m[i * n + columns.start..i * n + columns.end].to_vec())
+                .collect()
+        };
+
+        [
+            quadrant(0..h, 0..h),
+            quadrant(0..h, h..n),
+            quadrant(h..n, 0..h),
+            quadrant(h..n, h..n),
+        ]
+    }
+}
+
+impl<C: Element> Matrix<Polynomial<C>> {
+    /// Add two matrices while restricting the contained polynomials to the provided
+    /// ring degree. `a` and `b` must have the same size.
+    ///
+    /// Panics if they don't or if `ring` is 0, see [`Matrix::try_add_in_ring`].
+    pub fn add_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_add_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two matrices while restricting the contained polynomials to the provided
+    /// ring degree, returning an error if the sizes of `a` and `b` don't match or if
+    /// `ring` is 0.
+    pub fn try_add_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        Self::try_zip_with(a, b, |x, y| Polynomial::try_add_in_ring(x, y, ring))
+    }
+
+    /// Multiply two matrices element by element while restricting the contained polynomials
+    /// to the provided ring degree. `a` and `b` must have the same size.
+    ///
+    /// Panics if they don't or if `ring` is 0, see [`Matrix::try_hadamard_in_ring`].
+    pub fn hadamard_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_hadamard_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices element by element while restricting the contained polynomials
+    /// to the provided ring degree, returning an error if the sizes of `a` and `b` don't
+    /// match or if `ring` is 0.
+    pub fn try_hadamard_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        Self::try_zip_with(a, b, |x, y| Polynomial::try_mul_in_ring(x, y, ring))
     }
 
     /// Multiply two matrices while restricting the contained polynomials to the provided
     /// ring degree. The width of `a` must match the height of `b`.
-    pub fn mul_in_ring(a: &Matrix, b: &Matrix, ring: usize) -> Matrix {
-        assert_eq!(a.width, b.height);
-
-        let mut result = vec![Polynomial::zero(); b.width * a.height];
-
-        for i in 0..a.height {
-            for j in 0..b.width {
-                for k in 0..a.width {
-                    let mul = Polynomial::mul_in_ring(
-                        &a.content[k + i * a.width],
-                        &b.content[j + k * b.width],
-                        ring,
+    ///
+    /// Panics if it doesn't or if `ring` is 0, see [`Matrix::try_mul_in_ring`].
+    pub fn mul_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_mul_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices while restricting the contained polynomials to the provided
+    /// ring degree, returning an error if the width of `a` doesn't match the height of `b`
+    /// or if `ring` is 0.
+    ///
+    /// Coefficients with a faster product than the classic kernel use it, see
+    /// [`Element::mul_matrices_in_ring`]: large enough complex products are done by
+    /// evaluation and interpolation.
+    pub fn try_mul_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        Self::check_mul_size(a, b)?;
+        if let Some(product) = C::mul_matrices_in_ring(a, b, ring) {
+            return Ok(product);
+        }
+
+        Self::try_product_with(
+            a,
+            b,
+            |acc, x, y| acc.mul_accumulate_in_ring(x, y, ring),
+            Polynomial::trim,
+        )
+    }
+
+    /// Multiply two matrices with Strassen's algorithm while restricting the contained
+    /// polynomials to the provided ring degree, see [`Matrix::mul_strassen`].
+    ///
+    /// Panics if the width of `a` doesn't match the height of `b` or if `ring` is 0, see
+    /// [`Matrix::try_mul_strassen_in_ring`].
+    pub fn mul_strassen_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+        crossover: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_mul_strassen_in_ring(a, b, ring, crossover).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices with Strassen's algorithm while restricting the contained
+    /// polynomials to the provided ring degree, returning an error if the width of `a`
+    /// doesn't match the height of `b` or if `ring` is 0.
+    pub fn try_mul_strassen_in_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+        crossover: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        Self::check_mul_size(a, b)?;
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        // Reducing to the ring commutes with additions and subtractions, so only the block
+        // products need to be done in the ring.
+        Ok(Self::strassen_padded(a, b, crossover, &|x, y| {
+            Self::mul_in_ring(x, y, ring)
+        }))
+    }
+
+    /// Add two matrices while reducing the contained polynomials modulo `x^ring + 1`.
+    /// `a` and `b` must have the same size.
+    ///
+    /// Panics if they don't or if `ring` is 0, see [`Matrix::try_add_in_negacyclic_ring`].
+    pub fn add_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_add_in_negacyclic_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two matrices while reducing the contained polynomials modulo `x^ring + 1`,
+    /// returning an error if the sizes of `a` and `b` don't match or if `ring` is 0.
+    pub fn try_add_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        Self::try_zip_with(a, b, |x, y| {
+            Polynomial::try_add_in_negacyclic_ring(x, y, ring)
+        })
+    }
+
+    /// Multiply two matrices element by element while reducing the contained polynomials
+    /// modulo `x^ring + 1`. `a` and `b` must have the same size.
+    ///
+    /// Panics if they don't or if `ring` is 0, see
+    /// [`Matrix::try_hadamard_in_negacyclic_ring`].
+    pub fn hadamard_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_hadamard_in_negacyclic_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices element by element while reducing the contained polynomials
+    /// modulo `x^ring + 1`, returning an error if the sizes of `a` and `b` don't match or
+    /// if `ring` is 0.
+    pub fn try_hadamard_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        Self::try_zip_with(a, b, |x, y| {
+            Polynomial::try_mul_in_negacyclic_ring(x, y, ring)
+        })
+    }
+
+    /// Multiply two matrices while reducing the contained polynomials modulo `x^ring + 1`.
+    /// The width of `a` must match the height of `b`.
+    ///
+    /// Panics if it doesn't or if `ring` is 0, see [`Matrix::try_mul_in_negacyclic_ring`].
+    pub fn mul_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<C>> {
+        Self::try_mul_in_negacyclic_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two matrices while reducing the contained polynomials modulo `x^ring + 1`,
+    /// returning an error if the width of `a` doesn't match the height of `b` or if `ring`
+    /// is 0.
+    ///
+    /// Coefficients with a faster product than the classic kernel use it, see
+    /// [`Element::mul_matrices_in_negacyclic_ring`]: `Z_q` goes through the number theoretic
+    /// transform when the ring has [`crate::ntt::NttTables`]. Otherwise, the products are
+    /// accumulated unreduced, and every output element is reduced once.
+    pub fn try_mul_in_negacyclic_ring(
+        a: &Matrix<Polynomial<C>>,
+        b: &Matrix<Polynomial<C>>,
+        ring: usize,
+    ) -> Result<Matrix<Polynomial<C>>, Error> {
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        Self::check_mul_size(a, b)?;
+        if let Some(product) = C::mul_matrices_in_negacyclic_ring(a, b, ring) {
+            return Ok(product);
+        }
+
+        let a = a.map(|p| p.reduce_negacyclic_to(ring));
+        let b = b.map(|p| p.reduce_negacyclic_to(ring));
+
+        Self::try_product_with(
+            &a,
+            &b,
+            |acc, x, y| acc.mul_accumulate(x, y),
+            |p| p.reduce_negacyclic_to(ring).trim(),
+        )
+    }
+}
+
+impl Matrix<Polynomial> {
+    /// Returns the inverse of a square matrix with its polynomials restricted to the provided
+    /// ring degree, such that `mul_in_ring(self, inverse, ring)` is the identity.
+    ///
+    /// Panics if there is no inverse, see [`Matrix::try_inverse_in_ring`].
+    pub fn inverse_in_ring(&self, ring: usize) -> Matrix {
+        self.try_inverse_in_ring(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the inverse of the matrix in the ring of the provided degree, or an error if
+    /// the matrix isn't square, if `ring` is 0, or if the determinant isn't invertible modulo
+    /// `x^ring - 1` (see [`Polynomial::try_inverse_in_ring`]).
+    ///
+    /// The inverse is the adjugate multiplied by the inverse of the determinant.
+    pub fn try_inverse_in_ring(&self, ring: usize) -> Result<Matrix, Error> {
+        let determinant_inverse = self.try_determinant()?.try_inverse_in_ring(ring)?;
+        let mut adjugate = self.try_adjugate()?;
+
+        for p in adjugate.content.iter_mut() {
+            *p = Polynomial::try_mul_in_ring(&determinant_inverse, p, ring)?;
+        }
+
+        Ok(adjugate)
+    }
+
+    /// Generate a matrix filled with [`Polynomial::random`] entries, in row-major order.
+    ///
+    /// Use a [`RandomMatrixConfig`] to control how the entries are generated.
+    pub fn random<R: Rng + ?Sized>(width: usize, height: usize, rand: &mut R) -> Matrix {
+        RandomMatrixConfig::new(width, height).generate(rand)
+    }
+}
+
+impl<C: Field> Matrix<Polynomial<C>> {
+    /// Returns the determinant of a square matrix.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_determinant`].
+    pub fn determinant(&self) -> Polynomial<C> {
+        self.try_determinant().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the determinant of the matrix, or an error if it isn't square.
+    ///
+    /// The determinant is computed fraction-free with the Bareiss algorithm: each elimination
+    /// step divides by the previous pivot, which is exact in the polynomial ring. Entries are
+    /// compared to zero exactly when looking for a pivot.
+    pub fn try_determinant(&self) -> Result<Polynomial<C>, Error> {
+        self.check_square()?;
+
+        let n = self.width;
+        let mut m = self.content.clone();
+        let mut negate = false;
+        let mut previous_pivot = Polynomial::one();
+
+        for k in 0..n - 1 {
+            if m[k + k * n].is_zero() {
+                match (k + 1..n).find(|&i| !m[k + i * n].is_zero()) {
+                    Some(i) => {
+                        for j in k..n {
+                            m.swap(j + k * n, j + i * n);
+                        }
+                        negate = !negate;
+                    }
+                    None => return Ok(Polynomial::zero()),
+                }
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let numerator = Polynomial::sub(
+                        &Polynomial::mul(&m[k + k * n], &m[j + i * n]),
+                        &Polynomial::mul(&m[k + i * n], &m[j + k * n]),
                     );
-                    let add = Polynomial::add_in_ring(&result[j + i * b.width], &mul, ring);
 
-                    result[j + i * b.width] = add;
+                    // The division is exact, by a pivot that usually isn't monic: its
+                    // leading coefficient is divided out, not multiplied in.
+                    (m[j + i * n], _) =
+                        Polynomial::try_euclidean_division(&numerator, &previous_pivot)?;
                 }
             }
+
+            previous_pivot = m[k + k * n].clone();
         }
 
+        let determinant = m[n * n - 1].clone();
+
+        Ok(if negate {
+            determinant.neg()
+        } else {
+            determinant
+        })
+    }
+
+    /// Returns the matrix without its `row`-th row and `column`-th column.
+    fn minor(&self, row: usize, column: usize) -> Matrix<Polynomial<C>> {
+        let content = self
+            .content
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| idx / self.width != row && idx % self.width != column)
+            .map(|(_, p)| p.clone())
+            .collect();
+
         Matrix {
-            width: b.width,
-            height: a.height,
-            content: result,
+            width: self.width - 1,
+            height: self.height - 1,
+            content,
         }
     }
 
-    pub fn random(width: usize, height: usize, rand: &mut ThreadRng) -> Matrix {
-        let size = width * height;
-        let mut content = vec![Polynomial::zero(); size];
+    /// Returns the adjugate of a square matrix, the transpose of its cofactor matrix, such that
+    /// `self * adjugate` is `determinant * I`.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_adjugate`].
+    pub fn adjugate(&self) -> Matrix<Polynomial<C>> {
+        self.try_adjugate().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the adjugate of the matrix, or an error if it isn't square.
+    pub fn try_adjugate(&self) -> Result<Matrix<Polynomial<C>>, Error> {
+        self.check_square()?;
 
-        for i in 0..size {
-            content[i] = Polynomial::random(rand);
+        let n = self.width;
+        if n == 1 {
+            return Ok(Matrix::new(vec![Polynomial::one()], 1, 1));
         }
 
-        Matrix::new(content, width, height)
+        let mut content = Vec::with_capacity(n * n);
+
+        for i in 0..n {
+            for j in 0..n {
+                // The adjugate is transposed: its (i, j) entry is the (j, i) cofactor.
+                let cofactor = self.minor(j, i).try_determinant()?;
+
+                content.push(if (i + j) % 2 == 0 {
+                    cofactor
+                } else {
+                    cofactor.neg()
+                });
+            }
+        }
+
+        Ok(Matrix {
+            width: n,
+            height: n,
+            content,
+        })
+    }
+}
+
+impl Matrix<Complex> {
+    /// Returns the characteristic polynomial `det(xI - A)` of a square matrix.
+    ///
+    /// Panics if the matrix isn't square, see [`Matrix::try_characteristic_polynomial`].
+    pub fn characteristic_polynomial(&self) -> Polynomial {
+        self.try_characteristic_polynomial()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the characteristic polynomial `det(xI - A)` of the matrix, or an error if it
+    /// isn't square.
+    ///
+    /// The coefficients are computed from the highest degree down with the Faddeev-LeVerrier
+    /// algorithm: `M_1 = I`, `c_(n-k) = -tr(A M_k) / k` and `M_(k+1) = A M_k + c_(n-k) I`.
+    /// It takes `n` matrix products and its rounding errors grow with `n`, which is fine for
+    /// the small matrices it's meant for.
+    pub fn try_characteristic_polynomial(&self) -> Result<Polynomial, Error> {
+        self.check_square()?;
+
+        let n = self.width;
+        let mut coefficients = vec![Complex::ZERO; n + 1];
+        coefficients[n] = Complex::one();
+
+        let mut m = Matrix::identity(n);
+
+        for k in 1..=n {
+            // The product gives both the trace and the next `M`.
+            let mut am = Matrix::mul(self, &m);
+            let trace = am.trace();
+            coefficients[n - k] = Complex::new(-trace.re / k as f32, -trace.im / k as f32);
+
+            for i in 0..n {
+                am.content[i * (n + 1)] = am.content[i * (n + 1)] + coefficients[n - k];
+            }
+            m = am;
+        }
+
+        Ok(Polynomial::new(coefficients))
+    }
+}
+
+impl<E> Matrix<E> {
+    fn check_square(&self) -> Result<(), Error> {
+        if self.width != self.height {
+            return Err(Error::NotSquare((self.width, self.height)));
+        }
+
+        Ok(())
     }
 }
 
-impl Display for Matrix {
+impl<E: Display> Display for Matrix<E> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for i in 0..self.height {
+        for (i, row) in self.rows().enumerate() {
             write!(f, "[")?;
 
-            for j in 0..self.width {
-                let index = i * self.width + j;
-
+            for (j, element) in row.iter().enumerate() {
                 if j != 0 {
                     write!(f, ", ")?;
                 }
 
-                write!(f, "{}", self.content[index])?;
+                write!(f, "{element}")?;
             }
 
             if i != self.height - 1 {
@@ -163,13 +1228,15 @@ impl Display for Matrix {
 
 #[cfg(test)]
 mod tests {
-    use rand::thread_rng;
-
     use super::*;
+    use crate::{
+        random::{self, CoefficientDistribution, RandomPolynomialConfig},
+        test_util::poly,
+    };
 
     #[test]
     fn test_display() {
-        let mut rand = thread_rng();
+        let mut rand = random::seeded(0);
         let m1 = Matrix::random(2, 2, &mut rand);
         let m2 = Matrix::random(2, 2, &mut rand);
 
@@ -178,4 +1245,546 @@ mod tests {
         println!("m1 + m2:\n{}", Matrix::add(&m1, &m2));
         println!("m1 * m2:\n{}", Matrix::mul(&m1, &m2));
     }
+
+    #[test]
+    fn test_errors() {
+        let p = Polynomial::new(vec![Complex::new(1.0, 0.0)]);
+
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 3
+            }),
+            Matrix::try_new(vec![p.clone(); 3], 2, 2)
+        );
+        assert_eq!(
+            Err(Error::EmptyInput),
+            Matrix::<Polynomial>::try_new(vec![], 0, 0)
+        );
+
+        let m1 = Matrix::new(vec![p.clone(); 6], 3, 2);
+        let m2 = Matrix::new(vec![p; 4], 2, 2);
+        let mismatch = Err(Error::DimensionMismatch {
+            left: (3, 2),
+            right: (2, 2),
+        });
+
+        assert_eq!(mismatch, Matrix::try_add(&m1, &m2));
+        assert_eq!(mismatch, Matrix::try_add_in_ring(&m1, &m2, 2));
+        assert_eq!(mismatch, Matrix::try_mul(&m1, &m2));
+        assert_eq!(mismatch, Matrix::try_mul_in_ring(&m1, &m2, 2));
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Matrix::try_mul_in_ring(&m2, &m1, 0)
+        );
+        assert_eq!(Ok(Matrix::mul(&m2, &m1)), Matrix::try_mul(&m2, &m1));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_add_panics_on_mismatch() {
+        let mut rand = random::seeded(0);
+
+        Matrix::add(
+            &Matrix::random(2, 2, &mut rand),
+            &Matrix::random(3, 2, &mut rand),
+        );
+    }
+
+    #[test]
+    fn test_complex_matrices() {
+        let m1 = Matrix::new(
+            vec![
+                Complex::new(1.0, 1.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(0.0, -1.0),
+                Complex::new(3.0, 0.0),
+            ],
+            2,
+            2,
+        );
+        let identity = Matrix::new(
+            vec![
+                Complex::one(),
+                Complex::zero(),
+                Complex::zero(),
+                Complex::one(),
+            ],
+            2,
+            2,
+        );
+
+        assert_eq!(m1, Matrix::mul(&m1, &identity));
+        assert_eq!(m1, Matrix::mul(&identity, &m1));
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    Complex::new(2.0, 1.0),
+                    Complex::new(2.0, 0.0),
+                    Complex::new(0.0, -1.0),
+                    Complex::new(4.0, 0.0),
+                ],
+                2,
+                2,
+            ),
+            Matrix::add(&m1, &identity)
+        );
+
+        // [[1 + i, 2], [-i, 3]]^2 = [[2i - 2i, 2 + 2i + 6], [1 - i - 3i, -2i + 9]]
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    Complex::new(0.0, 0.0),
+                    Complex::new(8.0, 2.0),
+                    Complex::new(1.0, -4.0),
+                    Complex::new(9.0, -2.0),
+                ],
+                2,
+                2,
+            ),
+            Matrix::mul(&m1, &m1)
+        );
+        assert_eq!("[1 + 1i, 2]\n[-1i, 3]", m1.to_string());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = Matrix::new((0..6).map(|i| Complex::new(i as f32, 1.0)).collect(), 3, 2);
+        let t = m.transpose();
+
+        assert_eq!((2, 3), (t.width, t.height));
+        assert_eq!(
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0],
+            t.content.iter().map(|c| c.re).collect::<Vec<_>>()
+        );
+        assert_eq!(m, t.transpose());
+
+        let ct = m.conjugate_transpose();
+        assert!(ct.content.iter().all(|c| c.im == -1.0));
+
+        let p = Matrix::new(
+            vec![Polynomial::new(vec![
+                Complex::new(1.0, 2.0),
+                Complex::new(0.0, -3.0),
+            ])],
+            1,
+            1,
+        );
+        assert_eq!(
+            Matrix::new(
+                vec![Polynomial::new(vec![
+                    Complex::new(1.0, -2.0),
+                    Complex::new(0.0, 3.0),
+                ])],
+                1,
+                1,
+            ),
+            p.conjugate_transpose()
+        );
+    }
+
+    #[test]
+    fn test_transpose_in_place() {
+        for (width, height) in [
+            (1, 1),
+            (1, 7),
+            (7, 1),
+            (3, 5),
+            (5, 3),
+            (7, 7),
+            (40, 33),
+            (64, 64),
+        ] {
+            let m = Matrix::new(
+                (0..width * height)
+                    .map(|i| Complex::new(i as f32, 0.0))
+                    .collect(),
+                width,
+                height,
+            );
+
+            let mut in_place = m.clone();
+            in_place.transpose_in_place();
+
+            assert_eq!(m.transpose(), in_place, "{width}x{height}");
+        }
+    }
+
+    #[test]
+    fn test_mul_transposed() {
+        let mut rand = random::seeded(4);
+        let a = Matrix::random(3, 2, &mut rand);
+        let b = Matrix::random(4, 3, &mut rand);
+
+        assert_eq!(
+            Matrix::mul(&a, &b),
+            Matrix::mul_transposed(&a, &b.transpose())
+        );
+        assert!(Matrix::try_mul_transposed(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_blocked_mul() {
+        // Integer coefficients keep every sum exact, whatever the accumulation order.
+        let mut rand = random::seeded(5);
+        let entries = RandomPolynomialConfig::new()
+            .degrees(0..=4)
+            .distribution(CoefficientDistribution::Integer { bound: 5 });
+        let a = RandomMatrixConfig::new(37, 21)
+            .entries(entries.clone())
+            .generate(&mut rand);
+        let b = RandomMatrixConfig::new(19, 37)
+            .entries(entries)
+            .generate(&mut rand);
+
+        // Sizes that aren't multiples of the block size exercise the partial tiles.
+        for ring in [None, Some(1), Some(3)] {
+            let mut expected = Vec::new();
+            for i in 0..a.height {
+                for j in 0..b.width {
+                    let mut sum = Polynomial::zero();
+                    for k in 0..a.width {
+                        let x = &a.content[k + i * a.width];
+                        let y = &b.content[j + k * b.width];
+
+                        sum = match ring {
+                            None => Polynomial::add(&sum, &Polynomial::mul(x, y)),
+                            Some(ring) => Polynomial::add_in_ring(
+                                &sum,
+                                &Polynomial::mul_in_ring(x, y, ring),
+                                ring,
+                            ),
+                        };
+                    }
+                    expected.push(sum);
+                }
+            }
+
+            let product = match ring {
+                None => Matrix::mul(&a, &b),
+                Some(ring) => Matrix::mul_in_ring(&a, &b, ring),
+            };
+            assert_eq!(Matrix::new(expected, b.width, a.height), product);
+        }
+    }
+
+    #[test]
+    fn test_mul_strassen() {
+        let mut rand = random::seeded(6);
+        let entries = RandomPolynomialConfig::new()
+            .degrees(0..=3)
+            .distribution(CoefficientDistribution::Integer { bound: 3 });
+        let a = RandomMatrixConfig::new(7, 13)
+            .entries(entries.clone())
+            .generate(&mut rand);
+        let b = RandomMatrixConfig::new(10, 7)
+            .entries(entries)
+            .generate(&mut rand);
+
+        // Small crossovers go through several levels of recursion and padding.
+        for crossover in [1, 2, 3, STRASSEN_CROSSOVER] {
+            assert_eq!(Matrix::mul(&a, &b), Matrix::mul_strassen(&a, &b, crossover));
+            assert_eq!(
+                Matrix::mul_in_ring(&a, &b, 3),
+                Matrix::mul_strassen_in_ring(&a, &b, 3, crossover)
+            );
+        }
+
+        assert!(Matrix::try_mul_strassen(&b, &b, 2).is_err());
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Matrix::try_mul_strassen_in_ring(&a, &b, 0, 2)
+        );
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut m = Matrix::new((0..6).collect::<Vec<i32>>(), 3, 2);
+
+        assert_eq!(5, m[(1, 2)]);
+        assert_eq!(Some(&1), m.get(0, 1));
+        assert_eq!(None, m.get(0, 3));
+        assert_eq!(None, m.get(2, 0));
+
+        m[(0, 0)] = 10;
+        *m.get_mut(1, 0).unwrap() += 10;
+        assert_eq!(vec![10, 1, 2, 13, 4, 5], m.content);
+
+        assert_eq!(
+            vec![&[10, 1, 2][..], &[13, 4, 5][..]],
+            m.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![10, 13], vec![1, 4], vec![2, 5]],
+            m.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+
+        let m = m.reshape(2, 3);
+        assert_eq!(13, m[(1, 1)]);
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 6
+            }),
+            m.try_reshape(2, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 2) out of bounds")]
+    fn test_index_out_of_bounds() {
+        let m = Matrix::<Complex>::zeros(2, 3);
+
+        let _ = &m[(0, 2)];
+    }
+
+    #[test]
+    fn test_stacking() {
+        let a = Matrix::<Complex>::identity(2);
+        let b = Matrix::<Complex>::zeros(1, 2);
+        let c = Matrix::<Complex>::zeros(3, 1);
+
+        let h = Matrix::hstack(&[&a, &b]);
+        assert_eq!((3, 2), (h.width, h.height));
+        assert_eq!(Complex::new(1.0, 0.0), h[(1, 1)]);
+        assert_eq!(Complex::ZERO, h[(1, 2)]);
+
+        let v = Matrix::vstack(&[&h, &c]);
+        assert_eq!((3, 3), (v.width, v.height));
+        assert_eq!(v, Matrix::block(&[&[&a, &b], &[&c]]));
+
+        assert!(Matrix::try_hstack(&[&a, &c]).is_err());
+        assert!(Matrix::try_vstack(&[&a, &c]).is_err());
+        assert!(Matrix::try_block(&[&[&a], &[&c]]).is_err());
+        assert_eq!(Err(Error::EmptyInput), Matrix::<Complex>::try_block(&[]));
+    }
+
+    #[test]
+    fn test_hadamard_and_kronecker() {
+        let a = Matrix::new(
+            vec![
+                poly(&[1.0, 1.0]),
+                poly(&[2.0]),
+                poly(&[0.0, 1.0]),
+                poly(&[-1.0]),
+            ],
+            2,
+            2,
+        );
+        let b = Matrix::new(
+            vec![
+                poly(&[0.0, 1.0]),
+                poly(&[3.0]),
+                poly(&[1.0, 1.0]),
+                poly(&[1.0]),
+            ],
+            2,
+            2,
+        );
+
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    poly(&[0.0, 1.0, 1.0]),
+                    poly(&[6.0]),
+                    poly(&[0.0, 1.0, 1.0]),
+                    poly(&[-1.0])
+                ],
+                2,
+                2
+            ),
+            Matrix::hadamard(&a, &b)
+        );
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    poly(&[1.0, 1.0]),
+                    poly(&[6.0]),
+                    poly(&[1.0, 1.0]),
+                    poly(&[-1.0])
+                ],
+                2,
+                2
+            ),
+            Matrix::hadamard_in_ring(&a, &b, 2)
+        );
+        assert!(Matrix::try_hadamard(&a, &Matrix::zeros(1, 2)).is_err());
+
+        let identity = Matrix::<Complex>::identity(2);
+        let c = Matrix::new(
+            vec![
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(0.0, 1.0),
+            ],
+            3,
+            1,
+        );
+        let k = Matrix::kronecker(&identity, &c);
+
+        assert_eq!((6, 2), (k.width, k.height));
+        assert_eq!(Complex::new(2.0, 0.0), k[(0, 1)]);
+        assert_eq!(Complex::ZERO, k[(0, 4)]);
+        assert_eq!(Complex::new(0.0, 1.0), k[(1, 5)]);
+        assert_eq!(
+            Matrix::kronecker(&c, &identity).transpose(),
+            Matrix::kronecker(&c.transpose(), &identity)
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        // [[x, 1], [1, x]] has the determinant x2 - 1
+        let m = Matrix::new(
+            vec![
+                poly(&[0.0, 1.0]),
+                poly(&[1.0]),
+                poly(&[1.0]),
+                poly(&[0.0, 1.0]),
+            ],
+            2,
+            2,
+        );
+        assert_eq!(poly(&[-1.0, 0.0, 1.0]), m.determinant());
+
+        // A zero pivot requires a row swap, which negates the determinant.
+        let m = Matrix::new(
+            vec![
+                poly(&[0.0]),
+                poly(&[1.0]),
+                poly(&[2.0]),
+                poly(&[1.0]),
+                poly(&[0.0]),
+                poly(&[0.0, 1.0]),
+                poly(&[0.0, 1.0]),
+                poly(&[1.0]),
+                poly(&[0.0]),
+            ],
+            3,
+            3,
+        );
+        // Cofactor expansion along the first row: -(0 - x2) + 2(1 - 0) = x2 + 2
+        assert_eq!(poly(&[2.0, 0.0, 1.0]), m.determinant());
+
+        let singular = Matrix::new(vec![poly(&[1.0, 1.0]); 4], 2, 2);
+        assert_eq!(Polynomial::zero(), singular.determinant());
+
+        assert_eq!(
+            Err(Error::NotSquare((3, 2))),
+            Matrix::random(3, 2, &mut random::seeded(0)).try_determinant()
+        );
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let config = RandomMatrixConfig::new(3, 3).entries(
+            RandomPolynomialConfig::new()
+                .degrees(0..=2)
+                .distribution(CoefficientDistribution::Integer { bound: 3 }),
+        );
+        let m = config.generate(&mut random::seeded(1));
+
+        let determinant = m.determinant();
+        let identity_times_determinant = Matrix::new(
+            (0..9)
+                .map(|i| {
+                    if i % 4 == 0 {
+                        determinant.clone()
+                    } else {
+                        Polynomial::zero()
+                    }
+                })
+                .collect(),
+            3,
+            3,
+        );
+
+        assert_eq!(identity_times_determinant, Matrix::mul(&m, &m.adjugate()));
+        assert_eq!(identity_times_determinant, Matrix::mul(&m.adjugate(), &m));
+    }
+
+    #[test]
+    fn test_inverse_in_ring() {
+        // [[1, x], [0, 1]] is unimodular, its inverse is [[1, -x], [0, 1]]
+        let m = Matrix::new(
+            vec![poly(&[1.0]), poly(&[0.0, 1.0]), poly(&[0.0]), poly(&[1.0])],
+            2,
+            2,
+        );
+        assert_eq!(
+            Matrix::new(
+                vec![poly(&[1.0]), poly(&[0.0, -1.0]), poly(&[0.0]), poly(&[1.0])],
+                2,
+                2,
+            ),
+            m.inverse_in_ring(4)
+        );
+
+        // det = 2 + x, which is invertible modulo x3 - 1
+        let m = Matrix::new(
+            vec![poly(&[2.0, 1.0]), poly(&[1.0]), poly(&[0.0]), poly(&[1.0])],
+            2,
+            2,
+        );
+        let product = Matrix::mul_in_ring(&m, &m.inverse_in_ring(3), 3);
+
+        for (idx, p) in product.content.iter().enumerate() {
+            let expected = if idx % 3 == 0 { 1.0 } else { 0.0 };
+
+            assert!((p.coefficients[0] - Complex::new(expected, 0.0)).norm() < 1e-5);
+            assert!(p.coefficients[1..].iter().all(|c| c.norm() < 1e-5));
+        }
+
+        // det = 1 - x vanishes at 1
+        let m = Matrix::new(vec![poly(&[1.0, -1.0])], 1, 1);
+        assert_eq!(Err(Error::NotInvertible), m.try_inverse_in_ring(3));
+    }
+
+    #[test]
+    fn test_identity_and_trace() {
+        let identity = Matrix::<Complex>::identity(3);
+
+        assert_eq!(Complex::new(3.0, 0.0), identity.trace());
+        assert_eq!(Complex::one(), identity.content[4]);
+        assert_eq!(Complex::zero(), identity.content[5]);
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        // [[1, 2], [3, 4]] has the characteristic polynomial x2 - 5x - 2
+        let m = Matrix::new(
+            vec![
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(3.0, 0.0),
+                Complex::new(4.0, 0.0),
+            ],
+            2,
+            2,
+        );
+        assert_eq!(poly(&[-2.0, -5.0, 1.0]), m.characteristic_polynomial());
+
+        // Cayley-Hamilton: every matrix is a root of its characteristic polynomial.
+        let mut rand = random::seeded(2);
+        let distribution = CoefficientDistribution::Integer { bound: 3 };
+        let m = Matrix::new(
+            (0..16).map(|_| distribution.sample(&mut rand)).collect(),
+            4,
+            4,
+        );
+        let characteristic = m.characteristic_polynomial();
+
+        assert_eq!(4, characteristic.degree);
+        assert!(characteristic
+            .eval_matrix(&m)
+            .content
+            .iter()
+            .all(|c| c.norm() < 1e-3));
+        assert_eq!(
+            Err(Error::NotSquare((1, 2))),
+            Matrix::new(vec![Complex::ZERO; 2], 1, 2).try_characteristic_polynomial()
+        );
+    }
 }
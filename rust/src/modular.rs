@@ -0,0 +1,302 @@
+//! Integers modulo `q`, the coefficients of the rings `R_q = Z_q[x]/(x^n ± 1)` used by
+//! lattice cryptography.
+//!
+//! The modulus is a const parameter of [`Zq`], so that elements of different rings can't be
+//! mixed up and [`Element::zero`] doesn't need one at runtime. Products are reduced with
+//! Barrett's method, which replaces the division by `q` with a multiplication by a constant
+//! computed once per modulus.
+//!
+//! [`Element::zero`]: crate::element::Element::zero
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use rand::Rng;
+
+/// An integer modulo `Q`, stored as its representative in `[0, Q)`.
+///
+/// `Q` must be in `[2, 2^31)`, so that sums fit in 32 bits and products in 64 bits. It
+/// doesn't need to be prime: elements that aren't coprime with `Q` just have no inverse.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Zq<const Q: u32> {
+    value: u32,
+}
+
+impl<const Q: u32> Zq<Q> {
+    pub const MODULUS: u32 = Q;
+
+    pub const ZERO: Zq<Q> = Zq { value: 0 };
+
+    /// `floor(2^64 / Q)`, the Barrett constant. Evaluating it checks the modulus at compile
+    /// time.
+    const BARRETT: u64 = {
+        assert!(Q >= 2 && Q < 1 << 31, "the modulus must be in [2, 2^31)");
+        ((1u128 << 64) / Q as u128) as u64
+    };
+
+    /// Returns the class of `value` modulo `Q`.
+    pub fn new(value: i64) -> Zq<Q> {
+        Zq {
+            value: value.rem_euclid(Q as i64) as u32,
+        }
+    }
+
+    /// Returns the representative in `[0, Q)`.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns the centered representative, in `(-Q/2, Q/2]`, which is the small integer the
+    /// element stands for when it encodes noise or a signed message.
+    pub fn centered(&self) -> i32 {
+        if self.value > Q / 2 {
+            self.value as i32 - Q as i32
+        } else {
+            self.value as i32
+        }
+    }
+
+    /// Reduce any 64-bit integer modulo `Q` with Barrett's method.
+    ///
+    /// The estimated quotient `x * floor(2^64 / Q) / 2^64` is at most one below the actual
+    /// one, so a single conditional subtraction is left.
+    #[inline]
+    pub fn reduce(x: u64) -> u32 {
+        let quotient = ((x as u128 * Self::BARRETT as u128) >> 64) as u64;
+        let remainder = x - quotient * Q as u64;
+
+        if remainder >= Q as u64 {
+            (remainder - Q as u64) as u32
+        } else {
+            remainder as u32
+        }
+    }
+
+    /// Returns `self` raised to the power `exponent`, by squaring.
+    pub fn pow(&self, mut exponent: u64) -> Zq<Q> {
+        let mut base = *self;
+        let mut result = Zq::new(1);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the multiplicative inverse with the extended Euclidean algorithm, or `None`
+    /// if the element isn't coprime with `Q`.
+    pub fn inv(&self) -> Option<Zq<Q>> {
+        let (mut r0, mut r1) = (Q as i64, self.value as i64);
+        let (mut t0, mut t1) = (0, 1);
+
+        while r1 != 0 {
+            let quotient = r0 / r1;
+            (r0, r1) = (r1, r0 - quotient * r1);
+            (t0, t1) = (t1, t0 - quotient * t1);
+        }
+
+        (r0 == 1).then(|| Zq::new(t0))
+    }
+
+    /// Generate an element uniformly distributed in `[0, Q)`.
+    pub fn random<R: Rng + ?Sized>(rand: &mut R) -> Zq<Q> {
+        Zq {
+            value: rand.gen_range(0..Q),
+        }
+    }
+}
+
+impl<const Q: u32> Add for Zq<Q> {
+    type Output = Zq<Q>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = self.value + rhs.value;
+
+        Zq {
+            value: if sum >= Q { sum - Q } else { sum },
+        }
+    }
+}
+
+impl<const Q: u32> Sub for Zq<Q> {
+    type Output = Zq<Q>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const Q: u32> Neg for Zq<Q> {
+    type Output = Zq<Q>;
+
+    fn neg(self) -> Self::Output {
+        Zq {
+            value: if self.value == 0 { 0 } else { Q - self.value },
+        }
+    }
+}
+
+impl<const Q: u32> Mul for Zq<Q> {
+    type Output = Zq<Q>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Zq {
+            value: Self::reduce(self.value as u64 * rhs.value as u64),
+        }
+    }
+}
+
+impl<const Q: u32> Display for Zq<Q> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Returns whether `q` is prime, by trial division.
+pub(crate) fn is_prime(q: u32) -> bool {
+    q >= 2
+        && (2..)
+            .take_while(|d| d * d <= q)
+            .all(|d| !q.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Error, matrix::Matrix, polynomial::Polynomial, random};
+
+    /// The modulus of ML-KEM (Kyber).
+    type Z3329 = Zq<3329>;
+
+    fn poly<const Q: u32>(coefficients: &[i64]) -> Polynomial<Zq<Q>> {
+        Polynomial::new(coefficients.iter().map(|&c| Zq::new(c)).collect())
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let a = Z3329::new(3000);
+        let b = Z3329::new(-1000);
+
+        assert_eq!(2329, b.value());
+        assert_eq!(Z3329::new(2000), a + b);
+        assert_eq!(Z3329::new(671), a - b);
+        assert_eq!(Z3329::new(3000 * 2329 % 3329), a * b);
+        assert_eq!(Z3329::ZERO, a + -a);
+        assert_eq!(Z3329::new(1), a * a.inv().unwrap());
+        assert_eq!(a.inv().unwrap(), a.pow(3327));
+        assert_eq!(None, Z3329::ZERO.inv());
+
+        assert_eq!(-329, a.centered());
+        assert_eq!(1664, Z3329::new(1664).centered());
+        assert_eq!(-1664, Z3329::new(1665).centered());
+
+        // Composite moduli: only the units have an inverse.
+        assert_eq!(Some(Zq::<12>::new(5)), Zq::<12>::new(5).inv());
+        assert_eq!(None, Zq::<12>::new(4).inv());
+
+        let mut rand = random::seeded(44);
+        for _ in 0..1000 {
+            let x = rand.gen::<u64>();
+            assert_eq!((x % 8380417) as u32, Zq::<8380417>::reduce(x));
+            assert_eq!((x % 2147483647) as u32, Zq::<2147483647>::reduce(x));
+        }
+    }
+
+    #[test]
+    fn test_negacyclic_ring() {
+        // In Z_17[x]/(x^4 + 1), x^3 * x = -1.
+        let x = poly::<17>(&[0, 1]);
+        let x3 = poly::<17>(&[0, 0, 0, 1]);
+        assert_eq!(poly(&[16]), Polynomial::mul_in_negacyclic_ring(&x3, &x, 4));
+        assert_eq!(poly(&[1]), Polynomial::mul_in_ring(&x3, &x, 4));
+
+        // (1 + 2x + 3x^2 + 4x^3)(5 + 6x + 7x^2 + 8x^3) computed by hand, with x^4 = -1.
+        let a = poly::<3329>(&[1, 2, 3, 4]);
+        let b = poly::<3329>(&[5, 6, 7, 8]);
+        let product = poly(&[5 - 61, 16 - 52, 34 - 32, 60]);
+        assert_eq!(product, Polynomial::mul_in_negacyclic_ring(&a, &b, 4));
+
+        // The matrix product accumulates before reducing, and matches the entry by entry
+        // computation.
+        let mut rand = random::seeded(45);
+        let mut random_poly =
+            || Polynomial::new((0..8).map(|_| Z3329::random(&mut rand)).collect());
+        let m = Matrix::new((0..6).map(|_| random_poly()).collect(), 3, 2);
+        let v = Matrix::new((0..3).map(|_| random_poly()).collect(), 1, 3);
+
+        let product = Matrix::mul_in_negacyclic_ring(&m, &v, 8);
+        for i in 0..2 {
+            let expected = (0..3).fold(Polynomial::zero(), |acc, k| {
+                Polynomial::add(
+                    &acc,
+                    &Polynomial::mul_in_negacyclic_ring(&m[(i, k)], &v[(k, 0)], 8),
+                )
+            });
+            assert_eq!(expected.trim(), product[(i, 0)]);
+        }
+    }
+
+    #[test]
+    fn test_ring_matrices() {
+        // Matrices of polynomials over Z_q have the same ring arithmetic as complex ones.
+        let mut rand = random::seeded(46);
+        let mut random_poly =
+            || Polynomial::new((0..6).map(|_| Z3329::random(&mut rand)).collect());
+        let a = Matrix::new((0..9).map(|_| random_poly()).collect(), 3, 3);
+        let b = Matrix::new((0..9).map(|_| random_poly()).collect(), 3, 3);
+
+        let sum = Matrix::add_in_ring(&a, &b, 4);
+        let hadamard = Matrix::hadamard_in_ring(&a, &b, 4);
+        let negacyclic_sum = Matrix::add_in_negacyclic_ring(&a, &b, 4);
+        let negacyclic_hadamard = Matrix::hadamard_in_negacyclic_ring(&a, &b, 4);
+        for (i, j) in (0..3).flat_map(|i| (0..3).map(move |j| (i, j))) {
+            let (x, y) = (&a[(i, j)], &b[(i, j)]);
+            assert_eq!(Polynomial::add_in_ring(x, y, 4), sum[(i, j)]);
+            assert_eq!(Polynomial::mul_in_ring(x, y, 4), hadamard[(i, j)]);
+            assert_eq!(
+                Polynomial::add_in_negacyclic_ring(x, y, 4),
+                negacyclic_sum[(i, j)]
+            );
+            assert_eq!(
+                Polynomial::mul_in_negacyclic_ring(x, y, 4),
+                negacyclic_hadamard[(i, j)]
+            );
+        }
+
+        let product = Matrix::mul_in_ring(&a, &b, 4);
+        for (i, j) in (0..3).flat_map(|i| (0..3).map(move |j| (i, j))) {
+            let expected = (0..3).fold(Polynomial::zero(), |acc, k| {
+                Polynomial::add(&acc, &Polynomial::mul_in_ring(&a[(i, k)], &b[(k, j)], 4))
+            });
+            assert_eq!(expected, product[(i, j)]);
+        }
+        assert_eq!(product, Matrix::mul_strassen_in_ring(&a, &b, 4, 1));
+
+        // x^4 wraps to 1 in the cyclic ring and to -1 in the negacyclic one.
+        let x4 = Matrix::new(vec![poly::<17>(&[0, 0, 0, 0, 1])], 1, 1);
+        let one = Matrix::new(vec![poly::<17>(&[1])], 1, 1);
+        assert_eq!(
+            Matrix::new(vec![poly(&[2])], 1, 1),
+            Matrix::add_in_ring(&x4, &one, 4)
+        );
+        assert_eq!(
+            Matrix::new(vec![Polynomial::zero()], 1, 1),
+            Matrix::add_in_negacyclic_ring(&x4, &one, 4)
+        );
+        assert_eq!(
+            Matrix::new(vec![poly(&[16])], 1, 1),
+            Matrix::hadamard_in_negacyclic_ring(&x4, &one, 4)
+        );
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Matrix::try_add_in_negacyclic_ring(&x4, &one, 0)
+        );
+    }
+}
@@ -0,0 +1,534 @@
+//! Hermite and Smith normal forms of polynomial matrices.
+//!
+//! `K[x]` is a Euclidean ring for any field `K`, so any matrix of polynomials can be brought
+//! to a canonical form with invertible row (and column) operations. Both forms run Euclid's
+//! algorithm on the entries: the entry of lowest degree becomes the pivot, the others are
+//! reduced modulo it by subtracting multiples of its row or column, and so on until only
+//! their GCD is left. Those operations keep the transforms unimodular: their determinants
+//! are non-zero constants.
+//!
+//! The divisions are [`Polynomial::try_euclidean_division`], so the forms work over any
+//! [`Field`], and are exact with exact coefficients such as
+//! [`GaussianRational`](crate::exact::GaussianRational). With float coefficients, remainders
+//! rarely vanish exactly: like in [`Polynomial::extended_gcd`], the coefficients of the
+//! entries whose [`Field::magnitude`] is at most [`GCD_TOLERANCE`] times the largest one of
+//! the input are taken for zero.
+
+use std::ops::{Index, IndexMut, Range};
+
+use crate::{
+    complex::Complex,
+    element::Field,
+    error::Error,
+    matrix::Matrix,
+    polynomial::{Polynomial, GCD_TOLERANCE},
+};
+
+/// A Hermite normal form `H = UA`, with `U` unimodular.
+///
+/// `H` is in row echelon form: the first non-zero entry of each row, its pivot, is monic and
+/// to the right of the pivot of the row above, and the entries above a pivot have a lower
+/// degree than it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermiteForm<C = Complex> {
+    pub h: Matrix<Polynomial<C>>,
+    pub u: Matrix<Polynomial<C>>,
+}
+
+/// A Smith normal form `D = UAV`, with `U` and `V` unimodular.
+///
+/// `D` is diagonal, its non-zero entries are monic, come first, and each one divides the
+/// next one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmithForm<C = Complex> {
+    pub u: Matrix<Polynomial<C>>,
+    pub d: Matrix<Polynomial<C>>,
+    pub v: Matrix<Polynomial<C>>,
+}
+
+impl<C: Field> Matrix<Polynomial<C>> {
+    /// Returns the Hermite normal form of the matrix, along with the unimodular transform
+    /// that produces it.
+    ///
+    /// Panics if a pivot has no inverse, see [`Matrix::try_hermite_normal_form`].
+    pub fn hermite_normal_form(&self) -> HermiteForm<C> {
+        self.try_hermite_normal_form()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the Hermite normal form of the matrix, along with the unimodular transform
+    /// that produces it, or an error if the leading coefficient of a pivot has no inverse,
+    /// which can only happen when `C` isn't a field.
+    pub fn try_hermite_normal_form(&self) -> Result<HermiteForm<C>, Error> {
+        let mut h = Entries::from_matrix(self);
+        let mut u = Entries::identity(self.height);
+        let mut pivot_row = 0;
+
+        for j in 0..self.width {
+            if pivot_row == self.height {
+                break;
+            }
+
+            while let Some((i, _)) = h.pivot_position(pivot_row..self.height, j..j + 1) {
+                h.swap_rows(pivot_row, i);
+                u.swap_rows(pivot_row, i);
+
+                let mut reduced = true;
+                for i in pivot_row + 1..self.height {
+                    reduced &= reduce_row(&mut h, &mut u, i, (pivot_row, j))?;
+                }
+
+                if reduced {
+                    break;
+                }
+            }
+
+            if h[(pivot_row, j)].is_zero() {
+                continue;
+            }
+
+            let normalize = monic_factor(&h[(pivot_row, j)])?;
+            h.scale_row(pivot_row, &normalize);
+            u.scale_row(pivot_row, &normalize);
+
+            // Reduce the entries above the pivot modulo the pivot.
+            for i in 0..pivot_row {
+                reduce_row(&mut h, &mut u, i, (pivot_row, j))?;
+            }
+
+            pivot_row += 1;
+        }
+
+        Ok(HermiteForm {
+            h: h.into_matrix(),
+            u: u.into_matrix(),
+        })
+    }
+
+    /// Returns the Smith normal form of the matrix, along with the unimodular transforms that
+    /// produce it.
+    ///
+    /// Panics if a pivot has no inverse, see [`Matrix::try_smith_normal_form`].
+    pub fn smith_normal_form(&self) -> SmithForm<C> {
+        self.try_smith_normal_form()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the Smith normal form of the matrix, along with the unimodular transforms that
+    /// produce it, or an error if the leading coefficient of a pivot has no inverse, which
+    /// can only happen when `C` isn't a field.
+    pub fn try_smith_normal_form(&self) -> Result<SmithForm<C>, Error> {
+        let mut d = Entries::from_matrix(self);
+        let mut u = Entries::identity(self.height);
+        let mut v = Entries::identity(self.width);
+        let minus_one = Polynomial::new(vec![C::one().neg()]);
+
+        for t in 0..self.width.min(self.height) {
+            // Every round that leaves a non-zero remainder lowers the degree of the next
+            // pivot, until it divides the rest of its row and column.
+            while let Some((pi, pj)) = d.pivot_position(t..self.height, t..self.width) {
+                d.swap_rows(t, pi);
+                u.swap_rows(t, pi);
+                d.swap_columns(t, pj);
+                v.swap_columns(t, pj);
+
+                let mut reduced = true;
+                for i in t + 1..self.height {
+                    reduced &= reduce_row(&mut d, &mut u, i, (t, t))?;
+                }
+                for j in t + 1..self.width {
+                    reduced &= reduce_column(&mut d, &mut v, j, (t, t))?;
+                }
+
+                if !reduced {
+                    continue;
+                }
+
+                // Adding a row with an entry the pivot doesn't divide to the pivot row lets
+                // the next round find a pivot of lower degree.
+                let mut not_divided = None;
+                'rows: for i in t + 1..self.height {
+                    for j in t + 1..self.width {
+                        if !d.divides((t, t), (i, j))? {
+                            not_divided = Some(i);
+                            break 'rows;
+                        }
+                    }
+                }
+
+                match not_divided {
+                    Some(i) => {
+                        d.sub_row_multiple(t, i, &minus_one);
+                        u.sub_row_multiple(t, i, &minus_one);
+                    }
+                    None => break,
+                }
+            }
+
+            if d[(t, t)].is_zero() {
+                break;
+            }
+
+            let normalize = monic_factor(&d[(t, t)])?;
+            d.scale_row(t, &normalize);
+            u.scale_row(t, &normalize);
+        }
+
+        Ok(SmithForm {
+            u: u.into_matrix(),
+            d: d.into_matrix(),
+            v: v.into_matrix(),
+        })
+    }
+}
+
+/// A matrix of polynomials on which the forms are computed, whose entries are kept flushed.
+struct Entries<C> {
+    width: usize,
+    height: usize,
+    content: Vec<Polynomial<C>>,
+    /// Magnitude at most which coefficients are flushed to zero.
+    threshold: f32,
+}
+
+impl<C: Field> Entries<C> {
+    fn from_matrix(m: &Matrix<Polynomial<C>>) -> Entries<C> {
+        let largest = m
+            .content
+            .iter()
+            .map(Polynomial::largest_magnitude)
+            .fold(0.0, f32::max);
+        let threshold = largest * GCD_TOLERANCE;
+
+        Entries {
+            width: m.width,
+            height: m.height,
+            content: m
+                .content
+                .iter()
+                .map(|p| p.clone().flush(threshold))
+                .collect(),
+            threshold,
+        }
+    }
+
+    /// Returns the `n`x`n` identity, the starting point of the transforms.
+    fn identity(n: usize) -> Entries<C> {
+        Entries {
+            width: n,
+            height: n,
+            content: Matrix::identity(n).content,
+            // Transforms aren't bounded by the input, so only their exact zeros are flushed.
+            threshold: 0.0,
+        }
+    }
+
+    fn into_matrix(self) -> Matrix<Polynomial<C>> {
+        Matrix::new(self.content, self.width, self.height)
+    }
+
+    /// Returns the (row, column) of the non-zero entry of lowest degree among `rows` and
+    /// `columns`, or `None` if they're all zero. Ties go to the largest leading coefficient,
+    /// which keeps the quotients of the divisions by the pivot small.
+    fn pivot_position(&self, rows: Range<usize>, columns: Range<usize>) -> Option<(usize, usize)> {
+        rows.flat_map(|i| columns.clone().map(move |j| (i, j)))
+            .filter(|&index| !self[index].is_zero())
+            .min_by(|&a, &b| {
+                let (p, q) = (&self[a], &self[b]);
+                p.degree
+                    .cmp(&q.degree)
+                    .then(leading(q).magnitude().total_cmp(&leading(p).magnitude()))
+            })
+    }
+
+    /// Returns whether the entry at `divisor` divides the one at `index`.
+    fn divides(&self, divisor: (usize, usize), index: (usize, usize)) -> Result<bool, Error> {
+        let (_, remainder) = divide(&self[index], &self[divisor], self.threshold)?;
+
+        Ok(remainder.is_zero())
+    }
+
+    /// Subtract `factor` times row `i` from row `r`.
+    fn sub_row_multiple(&mut self, r: usize, i: usize, factor: &Polynomial<C>) {
+        for k in 0..self.width {
+            self[(r, k)] = sub_multiple(&self[(r, k)], factor, &self[(i, k)], self.threshold);
+        }
+    }
+
+    /// Subtract `factor` times column `j` from column `c`.
+    fn sub_column_multiple(&mut self, c: usize, j: usize, factor: &Polynomial<C>) {
+        for k in 0..self.height {
+            self[(k, c)] = sub_multiple(&self[(k, c)], factor, &self[(k, j)], self.threshold);
+        }
+    }
+
+    /// Multiply row `r` by the constant `factor`.
+    fn scale_row(&mut self, r: usize, factor: &C) {
+        for k in 0..self.width {
+            self[(r, k)] = self[(r, k)].map(|c| C::mul(c, factor));
+        }
+    }
+
+    fn swap_rows(&mut self, r: usize, i: usize) {
+        for k in 0..self.width {
+            self.content.swap(r * self.width + k, i * self.width + k);
+        }
+    }
+
+    fn swap_columns(&mut self, c: usize, j: usize) {
+        for k in 0..self.height {
+            self.content.swap(k * self.width + c, k * self.width + j);
+        }
+    }
+}
+
+impl<C> Index<(usize, usize)> for Entries<C> {
+    type Output = Polynomial<C>;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Polynomial<C> {
+        &self.content[row * self.width + column]
+    }
+}
+
+impl<C> IndexMut<(usize, usize)> for Entries<C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Polynomial<C> {
+        &mut self.content[row * self.width + column]
+    }
+}
+
+/// Reduce the entry of row `i` of `m` in the column of `pivot` modulo the pivot, by
+/// subtracting a multiple of the pivot row. The same operation is applied to `transform`.
+/// Returns whether the remainder is zero.
+fn reduce_row<C: Field>(
+    m: &mut Entries<C>,
+    transform: &mut Entries<C>,
+    i: usize,
+    (r, j): (usize, usize),
+) -> Result<bool, Error> {
+    let (q, remainder) = divide(&m[(i, j)], &m[(r, j)], m.threshold)?;
+    m.sub_row_multiple(i, r, &q);
+    transform.sub_row_multiple(i, r, &q);
+
+    // The division leaves exact zeros where the row operation leaves rounding errors.
+    m[(i, j)] = remainder;
+    Ok(m[(i, j)].is_zero())
+}
+
+/// Reduce the entry of column `j` of `m` in the row of `pivot` modulo the pivot, by
+/// subtracting a multiple of the pivot column. The same operation is applied to
+/// `transform`. Returns whether the remainder is zero.
+fn reduce_column<C: Field>(
+    m: &mut Entries<C>,
+    transform: &mut Entries<C>,
+    j: usize,
+    (i, c): (usize, usize),
+) -> Result<bool, Error> {
+    let (q, remainder) = divide(&m[(i, j)], &m[(i, c)], m.threshold)?;
+    m.sub_column_multiple(j, c, &q);
+    transform.sub_column_multiple(j, c, &q);
+
+    m[(i, j)] = remainder;
+    Ok(m[(i, j)].is_zero())
+}
+
+/// Returns `x - factor * y`, flushed.
+fn sub_multiple<C: Field>(
+    x: &Polynomial<C>,
+    factor: &Polynomial<C>,
+    y: &Polynomial<C>,
+    threshold: f32,
+) -> Polynomial<C> {
+    Polynomial::sub(x, &Polynomial::mul(factor, y)).flush(threshold)
+}
+
+/// Returns the (quotient, remainder) of the euclidean division of `numerator` by the
+/// non-zero `denominator`, with the remainder flushed.
+fn divide<C: Field>(
+    numerator: &Polynomial<C>,
+    denominator: &Polynomial<C>,
+    threshold: f32,
+) -> Result<(Polynomial<C>, Polynomial<C>), Error> {
+    let (quotient, remainder) = Polynomial::try_euclidean_division(numerator, denominator)?;
+
+    Ok((quotient, remainder.flush(threshold)))
+}
+
+fn leading<C>(p: &Polynomial<C>) -> &C {
+    &p.coefficients[p.degree]
+}
+
+/// Returns the constant that makes `p` monic once multiplied by it.
+fn monic_factor<C: Field>(p: &Polynomial<C>) -> Result<C, Error> {
+    leading(p).inv().ok_or(Error::NotInvertible)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{approx::Tolerance, assert_approx_eq, exact::GaussianRational, test_util::poly};
+
+    /// Rounding errors of the pivots' inverses accumulate in the transforms.
+    fn tolerance() -> Tolerance {
+        Tolerance::new().absolute(1e-3)
+    }
+
+    /// Asserts that the determinant of `m` is a non-zero constant.
+    fn assert_unimodular(m: &Matrix) {
+        let determinant = m.determinant();
+        assert!(
+            !determinant.is_zero()
+                && determinant.coefficients[1..]
+                    .iter()
+                    .all(|c| c.norm() < 1e-3),
+            "{determinant}"
+        );
+    }
+
+    #[test]
+    fn test_hermite_normal_form() {
+        // [x + 1, x; x2 - 1, 2; 1, x2]
+        let a = Matrix::new(
+            vec![
+                poly(&[1.0, 1.0]),
+                poly(&[0.0, 1.0]),
+                poly(&[-1.0, 0.0, 1.0]),
+                poly(&[2.0]),
+                poly(&[1.0]),
+                poly(&[0.0, 0.0, 1.0]),
+            ],
+            2,
+            3,
+        );
+        let HermiteForm { h, u } = a.hermite_normal_form();
+
+        assert_approx_eq!(Matrix::mul(&u, &a), h, tolerance());
+        assert_unimodular(&u);
+
+        // Upper triangular with monic pivots, and reduced entries above them.
+        assert_eq!(Polynomial::one(), h[(0, 0)]);
+        assert!(h[(1, 0)].is_zero() && h[(2, 0)].is_zero() && h[(2, 1)].is_zero());
+        let pivot = &h[(1, 1)];
+        assert_eq!(Complex::new(1.0, 0.0), pivot.coefficients[pivot.degree]);
+        assert!(h[(0, 1)].is_zero() || h[(0, 1)].degree < pivot.degree);
+    }
+
+    #[test]
+    fn test_smith_normal_form() {
+        // diag(x, x + 1) is equivalent to diag(1, x(x + 1))
+        let x = poly(&[0.0, 1.0]);
+        let a = Matrix::new(
+            vec![
+                x.clone(),
+                Polynomial::zero(),
+                Polynomial::zero(),
+                poly(&[1.0, 1.0]),
+            ],
+            2,
+            2,
+        );
+        let SmithForm { u, d, v } = a.smith_normal_form();
+
+        assert_approx_eq!(Matrix::mul(&Matrix::mul(&u, &a), &v), d, tolerance());
+        assert_unimodular(&u);
+        assert_unimodular(&v);
+        assert_approx_eq!(
+            Matrix::new(
+                vec![
+                    Polynomial::one(),
+                    Polynomial::zero(),
+                    Polynomial::zero(),
+                    poly(&[0.0, 1.0, 1.0]),
+                ],
+                2,
+                2,
+            ),
+            d,
+            tolerance()
+        );
+
+        // A rank 1 matrix has a single invariant factor.
+        let row = [
+            poly(&[-1.0, 1.0]),
+            poly(&[-1.0, 0.0, 1.0]),
+            Polynomial::zero(),
+        ];
+        let a = Matrix::new(
+            row.iter()
+                .chain(row.iter())
+                .map(|p| Polynomial::mul(p, &x))
+                .collect(),
+            3,
+            2,
+        );
+        let SmithForm { u, d, v } = a.smith_normal_form();
+
+        assert_approx_eq!(Matrix::mul(&Matrix::mul(&u, &a), &v), d, tolerance());
+        assert_approx_eq!(
+            Matrix::new(
+                vec![
+                    poly(&[0.0, -1.0, 1.0]),
+                    Polynomial::zero(),
+                    Polynomial::zero(),
+                    Polynomial::zero(),
+                    Polynomial::zero(),
+                    Polynomial::zero(),
+                ],
+                3,
+                2,
+            ),
+            d,
+            tolerance()
+        );
+    }
+
+    #[test]
+    fn test_exact_normal_forms() {
+        let exact = |coefficients: &[i64]| {
+            Polynomial::new(
+                coefficients
+                    .iter()
+                    .map(|&c| GaussianRational::from(c))
+                    .collect(),
+            )
+        };
+        let zero = Polynomial::zero();
+
+        // The matrix of `test_hermite_normal_form`, whose forms are now exact.
+        let a = Matrix::new(
+            vec![
+                exact(&[1, 1]),
+                exact(&[0, 1]),
+                exact(&[-1, 0, 1]),
+                exact(&[2]),
+                exact(&[1]),
+                exact(&[0, 0, 1]),
+            ],
+            2,
+            3,
+        );
+        let HermiteForm { h, u } = a.hermite_normal_form();
+
+        assert_eq!(Matrix::mul(&u, &a), h);
+        assert_eq!(Polynomial::one(), h[(0, 0)]);
+        assert!([(1, 0), (2, 0), (2, 1)].iter().all(|&i| h[i] == zero));
+        let determinant = u.determinant();
+        assert!(determinant.degree == 0 && !determinant.is_zero());
+
+        let a = Matrix::new(
+            vec![exact(&[0, 1]), zero.clone(), zero.clone(), exact(&[1, 1])],
+            2,
+            2,
+        );
+        let SmithForm { u, d, v } = a.smith_normal_form();
+
+        assert_eq!(Matrix::mul(&Matrix::mul(&u, &a), &v), d);
+        assert_eq!(
+            Matrix::new(
+                vec![Polynomial::one(), zero.clone(), zero, exact(&[0, 1, 1])],
+                2,
+                2
+            ),
+            d
+        );
+    }
+}
@@ -0,0 +1,654 @@
+//! Number-theoretic transforms, multiplying in `Z_q[x]/(x^n + 1)` in `O(n log n)`.
+//!
+//! When `q` is a prime with `2n | q - 1`, there is a primitive `2n`-th root of unity `ψ`
+//! modulo `q`, and `x^n + 1` splits into the `x - ψ^(2i + 1)`. The transform evaluates a
+//! polynomial at those `n` roots, where the negacyclic product is a pointwise product. The
+//! butterflies are the ones of the FFT, with the twiddle factors (powers of `ψ`) computed
+//! once per ring in [`NttTables`], and the values come out in bit-reversed order.
+//!
+//! With only `n | q - 1`, like Kyber's `q = 3329` for `n = 256`, the transform is
+//! incomplete: a primitive `n`-th root splits `x^n + 1` into `n / 2` factors `x^2 - γ_i`,
+//! the butterflies stop one layer early, and the products of the residues modulo these
+//! factors are products of polynomials of degree 1.
+//!
+//! Polynomials can be kept in the transformed domain as [`NttPolynomial`]s, which are
+//! [`crate::element::Element`]s themselves: a product of matrices of them with
+//! [`Matrix::mul`] is a product in the ring, without any transform.
+//!
+//! [`Polynomial::mul_in_negacyclic_ring`] and [`Matrix::mul_in_negacyclic_ring`] go through
+//! the transforms by themselves for the rings that have tables, which are computed once per
+//! `(Q, ring)` and shared, and fall back to the schoolbook product for the others.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use crate::{
+    error::Error,
+    matrix::Matrix,
+    modular::{is_prime, Zq},
+    polynomial::Polynomial,
+};
+
+/// The tables shared by the ring products, by `(Q, ring)`, `None` for the rings without
+/// tables. Statics are shared by all the `Q`s, hence the key and the type erasure.
+type SharedTables = Option<Arc<dyn Any + Send + Sync>>;
+static CACHE: OnceLock<Mutex<HashMap<(u32, usize), SharedTables>>> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    /// How many tables [`NttTables::cached`] handed out on this thread, for the tests to
+    /// tell which products went through the transforms.
+    static DISPATCHES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// The twiddle factors of the transforms for a ring `Z_Q[x]/(x^ring + 1)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NttTables<const Q: u32> {
+    ring: usize,
+    /// `ψ^bitrev(k)` for `k` in `[0, len)`, in the order the butterflies use them, `len`
+    /// being the length of the butterflies: `ring`, or `ring / 2` for incomplete transforms.
+    zetas: Vec<Zq<Q>>,
+    /// The inverses of `zetas`.
+    inverse_zetas: Vec<Zq<Q>>,
+    /// `1 / len`, which scales the inverse transform.
+    len_inverse: Zq<Q>,
+    /// The `γ_i` of the factors `x^2 - γ_i` of incomplete transforms, `None` for complete
+    /// ones.
+    gammas: Option<Arc<[Zq<Q>]>>,
+}
+
+impl<const Q: u32> NttTables<Q> {
+    /// Precompute the tables for the ring of degree `ring`.
+    ///
+    /// Panics if there are no such tables, see [`NttTables::try_new`].
+    pub fn new(ring: usize) -> NttTables<Q> {
+        Self::try_new(ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Precompute the tables for the ring of degree `ring`, or returns an error if `ring`
+    /// isn't a power of two, if `Q` isn't prime or if `ring` doesn't divide `Q - 1`.
+    ///
+    /// The transform is complete when `2 * ring` divides `Q - 1`, and incomplete otherwise.
+    pub fn try_new(ring: usize) -> Result<NttTables<Q>, Error> {
+        if !ring.is_power_of_two() || !is_prime(Q) {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        // Without a primitive `2n`-th root, a `n`-th one still splits `x^n + 1` into the
+        // quadratic factors `x^2 - γ_i`, the `γ_i` being the roots of `y^(n/2) + 1`.
+        let len = if (Q as u64 - 1).is_multiple_of(2 * ring as u64) {
+            ring
+        } else if ring >= 2 && (Q as u64 - 1).is_multiple_of(ring as u64) {
+            ring / 2
+        } else {
+            return Err(Error::InvalidRing(ring));
+        };
+
+        // `x^((Q - 1) / 2len)` has an order dividing `2len`, and exactly `2len` when its
+        // `len`-th power is -1 since `len` is a power of two. Half of the elements qualify.
+        let order = 2 * len as u64;
+        let minus_one = Zq::new(-1);
+        let psi = (2..Q as i64)
+            .map(|x| Zq::<Q>::new(x).pow((Q as u64 - 1) / order))
+            .find(|psi| psi.pow(len as u64) == minus_one)
+            .ok_or(Error::InvalidRing(ring))?;
+
+        let bits = len.trailing_zeros();
+        let zetas: Vec<_> = (0..len)
+            .map(|k| psi.pow(bit_reverse(k, bits) as u64))
+            .collect();
+        let inverse_zetas = zetas
+            .iter()
+            .map(|zeta| zeta.inv().ok_or(Error::NotInvertible))
+            .collect::<Result<_, _>>()?;
+
+        let mut tables = NttTables {
+            ring,
+            zetas,
+            inverse_zetas,
+            len_inverse: Zq::new(len as i64).inv().ok_or(Error::NotInvertible)?,
+            gammas: None,
+        };
+
+        if len < ring {
+            // The transform of `y` is its values at the roots of `y^len + 1`, in order.
+            let mut y = Polynomial::new(vec![Zq::ZERO, Zq::new(1)])
+                .reduce_negacyclic_to(len)
+                .coefficients;
+            y.resize(len, Zq::ZERO);
+            tables.butterflies(&mut y);
+            tables.gammas = Some(y.into());
+        }
+
+        Ok(tables)
+    }
+
+    /// Returns the tables of the ring, computed on the first call for this `(Q, ring)` and
+    /// shared afterwards, or `None` if [`NttTables::try_new`] fails.
+    pub(crate) fn cached(ring: usize) -> Option<Arc<NttTables<Q>>> {
+        let mut cache = CACHE
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let tables = cache.entry((Q, ring)).or_insert_with(|| {
+            let tables = Self::try_new(ring).ok()?;
+            Some(Arc::new(tables) as Arc<dyn Any + Send + Sync>)
+        });
+
+        let tables = tables.clone()?;
+        #[cfg(test)]
+        DISPATCHES.set(DISPATCHES.get() + 1);
+
+        Some(
+            tables
+                .downcast()
+                .expect("tables cached under another modulus"),
+        )
+    }
+
+    /// The degree `n` of the ring `Z_Q[x]/(x^n + 1)`.
+    pub fn ring(&self) -> usize {
+        self.ring
+    }
+
+    /// Returns whether the transform stops at the quadratic factors `x^2 - γ_i` of
+    /// `x^ring + 1`, because `2 * ring` doesn't divide `Q - 1`.
+    pub fn is_incomplete(&self) -> bool {
+        self.gammas.is_some()
+    }
+
+    /// Returns the transform of the polynomial, after having reduced it modulo
+    /// `x^ring + 1`.
+    pub fn forward(&self, p: &Polynomial<Zq<Q>>) -> NttPolynomial<Q> {
+        let mut values = p.reduce_negacyclic_to(self.ring).coefficients;
+        values.resize(self.ring, Zq::ZERO);
+        self.for_each_half(&mut values, |half| self.butterflies(half));
+
+        NttPolynomial {
+            values,
+            gammas: self.gammas.clone(),
+        }
+    }
+
+    /// Returns the polynomial whose transform is `p`, reduced modulo `x^ring + 1` and
+    /// trimmed.
+    ///
+    /// Panics if `p` doesn't come from tables of the same ring, see
+    /// [`NttTables::try_inverse`].
+    pub fn inverse(&self, p: &NttPolynomial<Q>) -> Polynomial<Zq<Q>> {
+        self.try_inverse(p).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the polynomial whose transform is `p`, or an error if `p` doesn't have one
+    /// value per root of the ring.
+    pub fn try_inverse(&self, p: &NttPolynomial<Q>) -> Result<Polynomial<Zq<Q>>, Error> {
+        let mut values = match p.values.len() {
+            // Constants have the same value at every root.
+            1 => return Ok(Polynomial::new(p.values.clone())),
+            len if len == self.ring => p.values.clone(),
+            found => {
+                return Err(Error::LengthMismatch {
+                    expected: self.ring,
+                    found,
+                })
+            }
+        };
+        self.for_each_half(&mut values, |half| self.inverse_butterflies(half));
+
+        Ok(Polynomial::new(values).trim())
+    }
+
+    /// Apply `transform` to the coefficients, or, for incomplete transforms, to the even
+    /// and odd ones separately: they are polynomials in `y = x^2` modulo `y^(n/2) + 1`, and
+    /// the residue of `a_e(x^2) + x a_o(x^2)` modulo `x^2 - γ` is `a_e(γ) + x a_o(γ)`.
+    fn for_each_half(&self, values: &mut [Zq<Q>], transform: impl Fn(&mut [Zq<Q>])) {
+        if self.gammas.is_none() {
+            return transform(values);
+        }
+
+        let (mut even, mut odd): (Vec<_>, Vec<_>) =
+            values.chunks(2).map(|pair| (pair[0], pair[1])).unzip();
+        transform(&mut even);
+        transform(&mut odd);
+
+        for (pair, (e, o)) in values.chunks_mut(2).zip(even.into_iter().zip(odd)) {
+            pair[0] = e;
+            pair[1] = o;
+        }
+    }
+
+    /// Cooley-Tukey butterflies, from the widest to the narrowest.
+    fn butterflies(&self, values: &mut [Zq<Q>]) {
+        let len = values.len();
+        let mut k = 0;
+        let mut half = len / 2;
+        while half >= 1 {
+            for start in (0..len).step_by(2 * half) {
+                k += 1;
+                let zeta = self.zetas[k];
+
+                for j in start..start + half {
+                    let t = zeta * values[j + half];
+                    values[j + half] = values[j] - t;
+                    values[j] = values[j] + t;
+                }
+            }
+            half /= 2;
+        }
+    }
+
+    /// Gentleman-Sande butterflies undo the forward ones in reverse order, leaving a factor
+    /// `len` to scale away.
+    fn inverse_butterflies(&self, values: &mut [Zq<Q>]) {
+        let len = values.len();
+        let mut k = len;
+        let mut half = 1;
+        while half < len {
+            for start in (0..len).step_by(2 * half).rev() {
+                k -= 1;
+                let zeta = self.inverse_zetas[k];
+
+                for j in start..start + half {
+                    let t = values[j];
+                    values[j] = t + values[j + half];
+                    values[j + half] = zeta * (t - values[j + half]);
+                }
+            }
+            half *= 2;
+        }
+
+        for value in values.iter_mut() {
+            *value = *value * self.len_inverse;
+        }
+    }
+
+    /// Multiply two polynomials in `Z_Q[x]/(x^ring + 1)` through their transforms.
+    pub fn mul(&self, a: &Polynomial<Zq<Q>>, b: &Polynomial<Zq<Q>>) -> Polynomial<Zq<Q>> {
+        self.inverse(&NttPolynomial::pointwise_mul(
+            &self.forward(a),
+            &self.forward(b),
+        ))
+    }
+
+    /// Returns the matrix of the transforms of the polynomials of `m`, to multiply it
+    /// several times without transforming it again.
+    pub fn forward_matrix(&self, m: &Matrix<Polynomial<Zq<Q>>>) -> Matrix<NttPolynomial<Q>> {
+        m.map(|p| self.forward(p))
+    }
+
+    /// Returns the matrix of the polynomials whose transforms are in `m`.
+    ///
+    /// Panics if one of them doesn't come from tables of the same ring, see
+    /// [`NttTables::try_inverse`].
+    pub fn inverse_matrix(&self, m: &Matrix<NttPolynomial<Q>>) -> Matrix<Polynomial<Zq<Q>>> {
+        m.map(|p| self.inverse(p))
+    }
+
+    /// Multiply two matrices of polynomials in `Z_Q[x]/(x^ring + 1)`, transforming every
+    /// polynomial once: a `n`x`m` by `m`x`p` product takes `nm + mp + np` transforms
+    /// instead of `nmp` ring products.
+    ///
+    /// Panics if the width of `a` doesn't match the height of `b`, see [`Matrix::try_mul`].
+    pub fn mul_matrices(
+        &self,
+        a: &Matrix<Polynomial<Zq<Q>>>,
+        b: &Matrix<Polynomial<Zq<Q>>>,
+    ) -> Matrix<Polynomial<Zq<Q>>> {
+        self.inverse_matrix(&Matrix::mul(
+            &self.forward_matrix(a),
+            &self.forward_matrix(b),
+        ))
+    }
+}
+
+/// A polynomial of `Z_Q[x]/(x^n + 1)` in the transformed domain, in the order of
+/// [`NttTables::forward`]: its values at the roots of `x^n + 1`, or, for incomplete
+/// transforms, the coefficients `c_0, c_1` of its residues `c_0 + c_1 x` modulo the factors
+/// `x^2 - γ_i`, one pair after the other.
+///
+/// Constants, like [`crate::element::Element::zero`] and `one`, are stored as a single value
+/// shared by all the roots, so that they don't need to know the degree of the ring.
+#[derive(Debug, Clone)]
+pub struct NttPolynomial<const Q: u32> {
+    values: Vec<Zq<Q>>,
+    /// The `γ_i` of incomplete transforms, `None` for complete transforms and constants.
+    gammas: Option<Arc<[Zq<Q>]>>,
+}
+
+impl<const Q: u32> NttPolynomial<Q> {
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: Zq<Q>) -> NttPolynomial<Q> {
+        NttPolynomial {
+            values: vec![c],
+            gammas: None,
+        }
+    }
+
+    /// The values at the roots, the coefficients of the residues of incomplete transforms,
+    /// or the single value of a constant.
+    pub fn values(&self) -> &[Zq<Q>] {
+        &self.values
+    }
+
+    /// Combine the values of two polynomials one by one, broadcasting constants. `f` must
+    /// be linear, like additions and subtractions, since a constant `c` is `c + 0 x` modulo
+    /// the factors of incomplete transforms.
+    pub(crate) fn zip_with(
+        a: &NttPolynomial<Q>,
+        b: &NttPolynomial<Q>,
+        f: impl Fn(Zq<Q>, Zq<Q>) -> Zq<Q>,
+    ) -> NttPolynomial<Q> {
+        let gammas = a.gammas.clone().or_else(|| b.gammas.clone());
+        let constant = |c: Zq<Q>, i: usize| spread(c, i, gammas.is_some());
+        let values = match (a.values.as_slice(), b.values.as_slice()) {
+            ([x], values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, &y)| f(constant(*x, i), y))
+                .collect(),
+            (values, [y]) => values
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| f(x, constant(*y, i)))
+                .collect(),
+            (x, y) => {
+                assert_eq!(x.len(), y.len(), "transforms of different rings");
+                x.iter().zip(y).map(|(&x, &y)| f(x, y)).collect()
+            }
+        };
+
+        NttPolynomial { values, gammas }
+    }
+
+    /// Multiply two polynomials in the ring, root by root, or factor by factor for
+    /// incomplete transforms, with `(a_0 + a_1 x)(b_0 + b_1 x) = a_0 b_0 + γ a_1 b_1 +
+    /// (a_0 b_1 + a_1 b_0) x` modulo `x^2 - γ`.
+    ///
+    /// Panics if they come from different rings.
+    pub fn pointwise_mul(a: &NttPolynomial<Q>, b: &NttPolynomial<Q>) -> NttPolynomial<Q> {
+        let gammas = match (&a.gammas, &b.gammas) {
+            (Some(gammas), _) | (_, Some(gammas)) => gammas,
+            (None, None) => return Self::product_with_scalars(a, b),
+        };
+        // Scaling by a constant doesn't mix the coefficients of the residues.
+        if a.values.len() == 1 || b.values.len() == 1 {
+            return Self::product_with_scalars(a, b);
+        }
+
+        assert_eq!(
+            a.values.len(),
+            b.values.len(),
+            "transforms of different rings"
+        );
+        let values = a
+            .values
+            .chunks(2)
+            .zip(b.values.chunks(2))
+            .zip(gammas.iter())
+            .flat_map(|((x, y), &gamma)| {
+                [x[0] * y[0] + gamma * x[1] * y[1], x[0] * y[1] + x[1] * y[0]]
+            })
+            .collect();
+
+        NttPolynomial {
+            values,
+            gammas: Some(gammas.clone()),
+        }
+    }
+
+    /// Multiply the values one by one, which is the product of complete transforms, or of
+    /// any transform by a constant.
+    fn product_with_scalars(a: &NttPolynomial<Q>, b: &NttPolynomial<Q>) -> NttPolynomial<Q> {
+        let values = match (a.values.as_slice(), b.values.as_slice()) {
+            ([x], values) | (values, [x]) => values.iter().map(|&y| *x * y).collect(),
+            (x, y) => {
+                assert_eq!(x.len(), y.len(), "transforms of different rings");
+                x.iter().zip(y).map(|(&x, &y)| x * y).collect()
+            }
+        };
+
+        NttPolynomial {
+            values,
+            gammas: a.gammas.clone().or_else(|| b.gammas.clone()),
+        }
+    }
+}
+
+impl<const Q: u32> PartialEq for NttPolynomial<Q> {
+    /// Constants are equal to the polynomials with the same value at every root, or with
+    /// the residue `c + 0 x` modulo every factor of an incomplete transform.
+    fn eq(&self, other: &Self) -> bool {
+        let incomplete = self.gammas.is_some() || other.gammas.is_some();
+        match (self.values.as_slice(), other.values.as_slice()) {
+            ([x], values) | (values, [x]) => values
+                .iter()
+                .enumerate()
+                .all(|(i, y)| spread(*x, i, incomplete) == *y),
+            (x, y) => x == y,
+        }
+    }
+}
+
+/// Returns the `i`-th value of the transform of the constant `c`.
+fn spread<const Q: u32>(c: Zq<Q>, i: usize, incomplete: bool) -> Zq<Q> {
+    if incomplete && i % 2 == 1 {
+        Zq::ZERO
+    } else {
+        c
+    }
+}
+
+/// Returns the `bits` lowest bits of `k` in reverse order.
+fn bit_reverse(k: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        k.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{element::Element, lwe, random};
+
+    /// The modulus of ML-DSA (Dilithium), with `2^13 | q - 1`.
+    const Q: u32 = 8380417;
+
+    fn random_poly<const Q: u32>(ring: usize, rand: &mut impl rand::Rng) -> Polynomial<Zq<Q>> {
+        Polynomial::new((0..ring).map(|_| Zq::random(rand)).collect())
+    }
+
+    /// The matrix product in the ring, with schoolbook products of polynomials.
+    fn schoolbook<const Q: u32>(
+        a: &Matrix<Polynomial<Zq<Q>>>,
+        b: &Matrix<Polynomial<Zq<Q>>>,
+        ring: usize,
+    ) -> Matrix<Polynomial<Zq<Q>>> {
+        let content = (0..a.height)
+            .flat_map(|i| (0..b.width).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                (0..a.width)
+                    .fold(Polynomial::zero(), |acc, k| {
+                        Polynomial::add(&acc, &Polynomial::mul(&a[(i, k)], &b[(k, j)]))
+                    })
+                    .reduce_negacyclic_to(ring)
+            })
+            .collect();
+
+        Matrix::new(content, b.width, a.height)
+    }
+
+    /// Returns whether the ring products have looked for the tables of `(q, ring)`, and if
+    /// so whether they found some.
+    fn cache_entry(q: u32, ring: usize) -> Option<bool> {
+        let cache = CACHE.get_or_init(Default::default).lock().unwrap();
+        cache.get(&(q, ring)).map(Option::is_some)
+    }
+
+    #[test]
+    fn test_ntt() {
+        let mut rand = random::seeded(45);
+
+        for ring in [1, 2, 8, 256] {
+            let tables = NttTables::<Q>::new(ring);
+            let a = random_poly(ring, &mut rand);
+            let b = random_poly(2 * ring, &mut rand);
+
+            assert_eq!(a.clone().trim(), tables.inverse(&tables.forward(&a)));
+            assert_eq!(
+                Polynomial::mul(&a, &b).reduce_negacyclic_to(ring),
+                tables.mul(&a, &b)
+            );
+        }
+
+        // The transform evaluates at the odd powers of ψ.
+        let tables = NttTables::<17>::new(4);
+        let x = Polynomial::new(vec![Zq::ZERO, Zq::new(1)]);
+        let roots = tables.forward(&x);
+        for root in roots.values() {
+            assert_eq!(Zq::new(-1), root.pow(4));
+        }
+
+        assert_eq!(Err(Error::InvalidRing(6)), NttTables::<Q>::try_new(6));
+        // Neither 2 * 32 nor 32 divide 17 - 1.
+        assert_eq!(Err(Error::InvalidRing(32)), NttTables::<17>::try_new(32));
+        assert_eq!(Err(Error::InvalidRing(2)), NttTables::<21>::try_new(2));
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 8
+            }),
+            tables.try_inverse(&NttTables::<17>::new(8).forward(&x))
+        );
+    }
+
+    #[test]
+    fn test_incomplete_ntt() {
+        let mut rand = random::seeded(48);
+
+        // 2 * 256 doesn't divide 3328 = 2^8 * 13, and 2 * 16 doesn't divide 16.
+        let kyber = NttTables::<3329>::new(256);
+        let small = NttTables::<17>::new(16);
+        assert!(kyber.is_incomplete() && small.is_incomplete());
+        assert!(!NttTables::<3329>::new(128).is_incomplete());
+
+        for _ in 0..4 {
+            let a = random_poly(256, &mut rand);
+            let b = random_poly(300, &mut rand);
+            assert_eq!(a.clone().trim(), kyber.inverse(&kyber.forward(&a)));
+            assert_eq!(
+                Polynomial::mul(&a, &b).reduce_negacyclic_to(256),
+                kyber.mul(&a, &b)
+            );
+
+            let a = random_poly(16, &mut rand);
+            let b = random_poly(16, &mut rand);
+            assert_eq!(
+                Polynomial::mul(&a, &b).reduce_negacyclic_to(16),
+                small.mul(&a, &b)
+            );
+        }
+
+        // Constants are `c + 0 x` modulo every factor, for sums as well as products.
+        let a = random_poly(256, &mut rand);
+        let a_hat = kyber.forward(&a);
+        let one = NttPolynomial::constant(Zq::new(1));
+        let a_plus_one = Polynomial::add(&a, &Polynomial::new(vec![Zq::new(1)]));
+        assert_eq!(kyber.forward(&a_plus_one), Element::add(&a_hat, &one));
+        assert_eq!(a_hat, Element::mul(&one, &a_hat));
+        assert_eq!(one, kyber.forward(&Polynomial::new(vec![Zq::new(1)])));
+        assert_ne!(
+            NttPolynomial::constant(Zq::new(2)),
+            kyber.forward(&Polynomial::new(vec![Zq::new(1)]))
+        );
+
+        let m = Matrix::new((0..6).map(|_| random_poly(256, &mut rand)).collect(), 2, 3);
+        let v = Matrix::new((0..2).map(|_| random_poly(256, &mut rand)).collect(), 1, 2);
+        assert_eq!(schoolbook(&m, &v, 256), kyber.mul_matrices(&m, &v));
+        let m_hat = kyber.forward_matrix(&m);
+        assert_eq!(m_hat, Matrix::mul(&Matrix::identity(3), &m_hat));
+    }
+
+    #[test]
+    fn test_kyber_rings_use_the_transform() {
+        let mut rand = random::seeded(50);
+        let (public_key, secret_key) = lwe::keygen(lwe::Parameters::MLWE_512, &mut rand);
+        let message = [0x5a; 32];
+        let ciphertext = lwe::encrypt(&public_key, &message, &mut rand);
+
+        assert_eq!(message.to_vec(), lwe::decrypt(&secret_key, &ciphertext));
+        assert_eq!(Some(true), cache_entry(3329, 256));
+    }
+
+    #[test]
+    fn test_matrices_in_ntt_form() {
+        let mut rand = random::seeded(46);
+        let ring = 16;
+        let tables = NttTables::<Q>::new(ring);
+        let mut random_matrix = |width, height| {
+            Matrix::new(
+                (0..width * height)
+                    .map(|_| random_poly(ring, &mut rand))
+                    .collect(),
+                width,
+                height,
+            )
+        };
+
+        let a = random_matrix(3, 2);
+        let b = random_matrix(2, 3);
+        let c = random_matrix(2, 2);
+
+        assert_eq!(schoolbook(&a, &b, ring), tables.mul_matrices(&a, &b));
+
+        // (A B) C stays in the transformed domain between the products.
+        let (a_hat, b_hat, c_hat) = (
+            tables.forward_matrix(&a),
+            tables.forward_matrix(&b),
+            tables.forward_matrix(&c),
+        );
+        let abc = tables.inverse_matrix(&Matrix::mul(&Matrix::mul(&a_hat, &b_hat), &c_hat));
+        assert_eq!(schoolbook(&schoolbook(&a, &b, ring), &c, ring), abc);
+
+        // Identity matrices hold constants, which work in any ring.
+        assert_eq!(c_hat, Matrix::mul(&Matrix::identity(2), &c_hat));
+    }
+
+    #[test]
+    fn test_ring_products_use_the_transform() {
+        let mut rand = random::seeded(47);
+        let a = random_poly::<Q>(32, &mut rand);
+        let b = random_poly::<Q>(40, &mut rand);
+        let m = Matrix::new(vec![a.clone(), b.clone()], 2, 1);
+        let v = Matrix::new(vec![b.clone(), a.clone()], 1, 2);
+
+        // The counter is per thread, so the other tests don't move it.
+        let dispatches = DISPATCHES.get();
+        assert_eq!(
+            Polynomial::mul(&a, &b).reduce_negacyclic_to(32),
+            Polynomial::mul_in_negacyclic_ring(&a, &b, 32)
+        );
+        assert_eq!(dispatches + 1, DISPATCHES.get());
+        assert_eq!(
+            schoolbook(&m, &v, 16),
+            Matrix::mul_in_negacyclic_ring(&m, &v, 16)
+        );
+        assert_eq!(dispatches + 2, DISPATCHES.get());
+
+        // Rings without tables fall back to the schoolbook product.
+        assert_eq!(
+            Polynomial::mul(&a, &b).reduce_negacyclic_to(6),
+            Polynomial::mul_in_negacyclic_ring(&a, &b, 6)
+        );
+        assert_eq!(
+            schoolbook(&m, &v, 6),
+            Matrix::mul_in_negacyclic_ring(&m, &v, 6)
+        );
+        assert_eq!(dispatches + 2, DISPATCHES.get());
+        assert!(NttTables::<Q>::cached(6).is_none());
+    }
+}
@@ -1,29 +1,52 @@
 use std::fmt::Display;
+#[cfg(feature = "parse")]
+use std::str::FromStr;
 
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
+#[cfg(feature = "parse")]
 use regex::Regex;
 
-use crate::complex::Complex;
-
+use crate::{
+    complex::Complex,
+    dft,
+    element::{Element, Field},
+    error::Error,
+    matrix::Matrix,
+    random::RandomPolynomialConfig,
+};
+
+/// Relative magnitude below which the coefficients of remainders are taken for zero when
+/// computing greatest common divisors, see [`Polynomial::extended_gcd`].
+pub const GCD_TOLERANCE: f32 = 1e-4;
+
+/// A polynomial, with complex coefficients by default.
+///
+/// Any [`Element`] can be a coefficient: additions and products only need a ring, divisions
+/// and greatest common divisors need a [`Field`].
 #[repr(C)]
 #[derive(Debug, PartialEq, Clone)]
-pub struct Polynomial {
+pub struct Polynomial<C = Complex> {
     pub degree: usize,
-    pub coefficients: Vec<Complex>,
+    pub coefficients: Vec<C>,
 }
 
-impl Polynomial {
-    pub fn zero() -> Polynomial {
+impl<C: Element> Polynomial<C> {
+    pub fn zero() -> Polynomial<C> {
         Polynomial {
             degree: 0,
-            coefficients: vec![Complex::ZERO],
+            coefficients: vec![C::zero()],
         }
     }
 
+    /// The constant polynomial 1.
+    pub fn one() -> Polynomial<C> {
+        Polynomial::new(vec![C::one()])
+    }
+
     /// Create a new complex polynomial, with its degree based on the number of coefficients.
     ///
     /// The coefficients are stored from lowest degree (0) to highest degree.
-    pub fn new(coefficients: Vec<Complex>) -> Polynomial {
+    pub fn new(coefficients: Vec<C>) -> Polynomial<C> {
         if coefficients.is_empty() {
             Polynomial::zero()
         } else {
@@ -34,17 +57,30 @@ impl Polynomial {
         }
     }
 
+    /// Returns the polynomial with `f` applied to every coefficient, to convert between
+    /// coefficient types. The degree is kept, even if some leading coefficients become zero.
+    pub fn map<D: Element>(&self, f: impl FnMut(&C) -> D) -> Polynomial<D> {
+        Polynomial::new(self.coefficients.iter().map(f).collect())
+    }
+
+    /// Returns whether all the coefficients of the polynomial are zero.
+    pub fn is_zero(&self) -> bool {
+        let zero = C::zero();
+        self.coefficients.iter().all(|c| *c == zero)
+    }
+
     /// Trims a polynomial if needed, removing the highest degree terms with a 0 coefficient
     /// and adjusting the degree of the polynomial if needed.
-    pub fn trim(self) -> Polynomial {
+    pub fn trim(self) -> Polynomial<C> {
         if self.degree == 0 {
             return self;
         }
 
+        let zero = C::zero();
         let mut degree = self.degree;
         let mut coeff_clone = self.coefficients.clone();
         while let Some(last) = coeff_clone.pop() {
-            if last != Complex::ZERO || degree == 0 {
+            if last != zero || degree == 0 {
                 break;
             }
 
@@ -57,7 +93,7 @@ impl Polynomial {
     /// Add two polynomials, summing both their coefficients one by one.
     ///
     /// The resulting polynomial is trimmed to the degree of its highest non-zero coefficient.
-    pub fn add(a: &Polynomial, b: &Polynomial) -> Polynomial {
+    pub fn add(a: &Polynomial<C>, b: &Polynomial<C>) -> Polynomial<C> {
         let mut coefficients = vec![];
 
         let mut iter1 = a.coefficients.iter();
@@ -69,13 +105,14 @@ impl Polynomial {
         }
 
         // Pad the shortest one with zeroes
-        let zero_iter = std::iter::repeat(&Complex::ZERO);
+        let zero = C::zero();
+        let zero_iter = std::iter::repeat(&zero);
         let iter2 = zero_iter
             .take(b.degree.abs_diff(a.degree))
             .chain(iter2.rev());
 
         for (c1, c2) in iter1.rev().zip(iter2) {
-            coefficients.push(c1 + c2);
+            coefficients.push(C::add(c1, c2));
         }
 
         let res = Polynomial::new(coefficients.into_iter().rev().collect());
@@ -83,33 +120,88 @@ impl Polynomial {
         res.trim()
     }
 
+    /// Subtract `b` from `a`.
+    ///
+    /// The resulting polynomial is trimmed to the degree of its highest non-zero coefficient.
+    pub fn sub(a: &Polynomial<C>, b: &Polynomial<C>) -> Polynomial<C> {
+        Self::add(a, &b.neg())
+    }
+
     /// Multiply two polynomials, done using the "schoolbook" algorithm.
     ///
     /// The resulting polynomial is trimmed to the degree of its highest non-zero coefficient.
-    pub fn mul(a: &Polynomial, b: &Polynomial) -> Polynomial {
+    pub fn mul(a: &Polynomial<C>, b: &Polynomial<C>) -> Polynomial<C> {
         let len = match (a.coefficients.len() + b.coefficients.len()).checked_sub(1) {
             Some(len) => len,
             None => return Polynomial::zero(),
         };
 
-        let mut coefficients = vec![Complex::ZERO; len];
+        let mut coefficients = vec![C::zero(); len];
 
         // "Schoolbook" algorithm
         for (i, c1) in a.coefficients.iter().enumerate() {
             for (j, c2) in b.coefficients.iter().enumerate() {
-                coefficients[i + j] = coefficients[i + j] + c1 * c2;
+                C::mul_add(&mut coefficients[i + j], c1, c2);
             }
         }
 
         Polynomial::new(coefficients).trim()
     }
 
+    /// Add `a * b` to the polynomial in place, only growing its coefficients when the
+    /// product doesn't fit. The result isn't trimmed, so that repeated accumulations keep
+    /// reusing the same buffer.
+    pub fn mul_accumulate(&mut self, a: &Polynomial<C>, b: &Polynomial<C>) {
+        let len = (a.coefficients.len() + b.coefficients.len()).saturating_sub(1);
+        if self.coefficients.len() < len {
+            self.coefficients.resize(len, C::zero());
+            self.degree = len - 1;
+        }
+
+        for (i, c1) in a.coefficients.iter().enumerate() {
+            for (c, c2) in self.coefficients[i..].iter_mut().zip(&b.coefficients) {
+                C::mul_add(c, c1, c2);
+            }
+        }
+    }
+
+    /// Add `a * b` reduced to the provided ring degree to the polynomial in place, like
+    /// [`Polynomial::mul_accumulate`]. The polynomial must already be reduced to the ring.
+    pub(crate) fn mul_accumulate_in_ring(
+        &mut self,
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) {
+        let len = (a.coefficients.len() + b.coefficients.len())
+            .saturating_sub(1)
+            .min(ring);
+        if self.coefficients.len() < len {
+            self.coefficients.resize(len, C::zero());
+            self.degree = len - 1;
+        }
+
+        // Reducing modulo `x^ring - 1` wraps the exponents around.
+        for (i, c1) in a.coefficients.iter().enumerate() {
+            let mut idx = i % ring;
+
+            for c2 in &b.coefficients {
+                C::mul_add(&mut self.coefficients[idx], c1, c2);
+
+                idx += 1;
+                if idx == ring {
+                    idx = 0;
+                }
+            }
+        }
+    }
+
     /// Negates all the coefficients of a polynomial.
-    pub fn neg(&self) -> Polynomial {
+    pub fn neg(&self) -> Polynomial<C> {
         let mut result = self.clone();
 
         result.coefficients.iter_mut().for_each(|c| {
-            *c = c.neg();
+            *c = C::neg(c);
         });
 
         result
@@ -120,48 +212,227 @@ impl Polynomial {
     /// For example, reducing `-3x4 + 6x3 + 3x2 - 6x` with a ring of degree 4 results
     /// in the polynomial `6x3 + 3x2 - 6x -3`. We obtain this result by doing the
     /// euclidian division of `self` by `x^ring - 1`, and returning the remainder.
-    pub fn reduce_to(&self, ring: usize) -> Polynomial {
-        if self.degree < ring {
-            return self.to_owned();
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_reduce_to`].
+    pub fn reduce_to(&self, ring: usize) -> Polynomial<C> {
+        self.try_reduce_to(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Reduce the provided polynomial to the provided ring degree, returning an error if
+    /// `ring` is 0.
+    pub fn try_reduce_to(&self, ring: usize) -> Result<Polynomial<C>, Error> {
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
         }
 
-        let mut denominator = vec![Complex::ZERO; ring + 1];
-        denominator[0] = Complex::new(-1.0, 0.0);
-        denominator[ring] = Complex::new(1.0, 0.0);
+        if self.degree < ring {
+            return Ok(self.to_owned());
+        }
 
-        let (_, remainder) = Self::euclidean_division(self, &Polynomial::new(denominator));
+        // `x^ring = 1`: fold the coefficients from the highest degree down, the way the long
+        // division by `x^ring - 1` would, so that only ring operations are needed.
+        let mut coefficients = self.coefficients.clone();
+        for k in (ring..coefficients.len()).rev() {
+            coefficients[k - ring] = C::add(&coefficients[k - ring], &coefficients[k]);
+        }
+        coefficients.truncate(ring);
 
-        remainder
+        Ok(Polynomial::new(coefficients).trim())
     }
 
     /// Add two polynomials, after having reduced them to the provided ring degree.
     ///
     /// The result is a polynomial trimmed to the degree of its highest non-zero coefficient.
-    pub fn add_in_ring(a: &Polynomial, b: &Polynomial, ring: usize) -> Polynomial {
-        let a = a.reduce_to(ring);
-        let b = b.reduce_to(ring);
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_add_in_ring`].
+    pub fn add_in_ring(a: &Polynomial<C>, b: &Polynomial<C>, ring: usize) -> Polynomial<C> {
+        Self::try_add_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        Self::add(&a, &b)
+    /// Add two polynomials, after having reduced them to the provided ring degree, returning
+    /// an error if `ring` is 0.
+    pub fn try_add_in_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Result<Polynomial<C>, Error> {
+        let a = a.try_reduce_to(ring)?;
+        let b = b.try_reduce_to(ring)?;
+
+        Ok(Self::add(&a, &b))
     }
 
     /// Multiply two polynomials, after having reduced them to the provided ring degree.
     ///
     /// The result is reduced to the provided ring degree. After this, the result is a
     /// polynomial trimmed to the degree of its highest non-zero coefficient.
-    pub fn mul_in_ring(a: &Polynomial, b: &Polynomial, ring: usize) -> Polynomial {
-        let a = a.reduce_to(ring);
-        let b = b.reduce_to(ring);
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_mul_in_ring`].
+    pub fn mul_in_ring(a: &Polynomial<C>, b: &Polynomial<C>, ring: usize) -> Polynomial<C> {
+        Self::try_mul_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two polynomials, after having reduced them to the provided ring degree,
+    /// returning an error if `ring` is 0.
+    pub fn try_mul_in_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Result<Polynomial<C>, Error> {
+        let a = a.try_reduce_to(ring)?;
+        let b = b.try_reduce_to(ring)?;
 
         let res = Self::mul(&a, &b);
 
-        res.reduce_to(ring)
+        res.try_reduce_to(ring)
+    }
+
+    /// Reduce the polynomial modulo `x^ring + 1`, the negacyclic counterpart of
+    /// [`Polynomial::reduce_to`]: every wrap around the ring negates the coefficient.
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_reduce_negacyclic_to`].
+    pub fn reduce_negacyclic_to(&self, ring: usize) -> Polynomial<C> {
+        self.try_reduce_negacyclic_to(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Reduce the polynomial modulo `x^ring + 1`, returning an error if `ring` is 0.
+    pub fn try_reduce_negacyclic_to(&self, ring: usize) -> Result<Polynomial<C>, Error> {
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        if self.degree < ring {
+            return Ok(self.to_owned());
+        }
+
+        // `x^ring = -1`: fold the coefficients from the highest degree down, subtracting.
+        let mut coefficients = self.coefficients.clone();
+        for k in (ring..coefficients.len()).rev() {
+            coefficients[k - ring] = C::sub(&coefficients[k - ring], &coefficients[k]);
+        }
+        coefficients.truncate(ring);
+
+        Ok(Polynomial::new(coefficients).trim())
+    }
+
+    /// Add two polynomials modulo `x^ring + 1`, after having reduced them to the ring.
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_add_in_negacyclic_ring`].
+    pub fn add_in_negacyclic_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Polynomial<C> {
+        Self::try_add_in_negacyclic_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two polynomials modulo `x^ring + 1`, after having reduced them to the ring,
+    /// returning an error if `ring` is 0.
+    pub fn try_add_in_negacyclic_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Result<Polynomial<C>, Error> {
+        let a = a.try_reduce_negacyclic_to(ring)?;
+        let b = b.try_reduce_negacyclic_to(ring)?;
+
+        Ok(Self::add(&a, &b))
+    }
+
+    /// Multiply two polynomials modulo `x^ring + 1`, after having reduced them to the ring.
+    ///
+    /// Panics if `ring` is 0, see [`Polynomial::try_mul_in_negacyclic_ring`].
+    pub fn mul_in_negacyclic_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Polynomial<C> {
+        Self::try_mul_in_negacyclic_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply two polynomials modulo `x^ring + 1`, after having reduced them to the ring,
+    /// returning an error if `ring` is 0.
+    ///
+    /// Coefficients with a faster product than the schoolbook one use it, see
+    /// [`Element::mul_polynomials_in_negacyclic_ring`]: `Z_q` goes through the number
+    /// theoretic transform when the ring has [`crate::ntt::NttTables`].
+    pub fn try_mul_in_negacyclic_ring(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+        ring: usize,
+    ) -> Result<Polynomial<C>, Error> {
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+        if let Some(product) = C::mul_polynomials_in_negacyclic_ring(a, b, ring) {
+            return Ok(product);
+        }
+
+        let a = a.try_reduce_negacyclic_to(ring)?;
+        let b = b.try_reduce_negacyclic_to(ring)?;
+
+        Self::mul(&a, &b).try_reduce_negacyclic_to(ring)
+    }
+
+    /// Returns the formal derivative of the polynomial, `k c_k` being `c_k` added `k` times,
+    /// which vanishes for multiples of the characteristic of `C`.
+    pub fn derivative(&self) -> Polynomial<C> {
+        let mut factor = C::zero();
+        let coefficients = self.coefficients[1..]
+            .iter()
+            .map(|c| {
+                factor = C::add(&factor, &C::one());
+                C::mul(&factor, c)
+            })
+            .collect();
+
+        Polynomial::new(coefficients).trim()
+    }
+
+    /// Evaluate the polynomial at `x`, with Horner's method.
+    pub fn eval(&self, x: C) -> C {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(C::zero(), |acc, c| C::add(&C::mul(&acc, &x), c))
+    }
+}
+
+impl<C: Field> Polynomial<C> {
+    /// Trims a polynomial like [`Polynomial::trim`], but also removes the highest degree terms
+    /// whose [`Field::magnitude`] is at most `eps`, such as the rounding errors left where
+    /// exact computations would have cancelled out.
+    pub fn trim_with(self, eps: f32) -> Polynomial<C> {
+        let degree = self
+            .coefficients
+            .iter()
+            .rposition(|c| c.magnitude() > eps)
+            .unwrap_or(0);
+
+        Polynomial::new(self.coefficients.into_iter().take(degree + 1).collect())
     }
 
     /// Returns the (quotient, remainder) of the euclidean division of `numerator` by `denominator`.
+    ///
+    /// Panics if `denominator` is zero, see [`Polynomial::try_euclidean_division`].
     pub fn euclidean_division(
-        numerator: &Polynomial,
-        denominator: &Polynomial,
-    ) -> (Polynomial, Polynomial) {
+        numerator: &Polynomial<C>,
+        denominator: &Polynomial<C>,
+    ) -> (Polynomial<C>, Polynomial<C>) {
+        Self::try_euclidean_division(numerator, denominator).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the (quotient, remainder) of the euclidean division of `numerator` by
+    /// `denominator`, or an error if `denominator` is zero or if its leading coefficient
+    /// isn't invertible.
+    ///
+    /// Both results are trimmed to the degree of their highest non-zero coefficient.
+    pub fn try_euclidean_division(
+        numerator: &Polynomial<C>,
+        denominator: &Polynomial<C>,
+    ) -> Result<(Polynomial<C>, Polynomial<C>), Error> {
         // "Long division" methods for polynomials.
         // Take the numerator and the denominator.
         // While the numerator's degree is higher or equal to the denominator's:
@@ -170,68 +441,268 @@ impl Polynomial {
         // 3. Substract the result multiplied to the denominator from the numerator
         // The remainder is what's left from the numerator at the end of the iterations.
 
-        if numerator.degree < denominator.degree {
-            return (numerator.to_owned(), Polynomial::zero());
+        if denominator.is_zero() {
+            return Err(Error::DivisionByZero);
         }
 
-        let quotient_len = numerator.degree - denominator.degree + 1;
+        let denominator = denominator.clone().trim();
+        let divider_highest_term = &denominator.coefficients[denominator.degree];
 
-        let mut quotient: Vec<Complex> = vec![Complex::ZERO; quotient_len];
-        let mut remainder = numerator.to_owned();
+        if numerator.degree < denominator.degree {
+            return Ok((Polynomial::zero(), numerator.clone().trim()));
+        }
 
-        let divider_highest_term = denominator.coefficients.last().unwrap();
-        let divider_highest_term_degree = denominator.degree;
+        let mut quotient = vec![C::zero(); numerator.degree - denominator.degree + 1];
+        let mut remainder = numerator.coefficients.clone();
 
-        while remainder.degree >= denominator.degree {
-            let rem_highest_term = remainder.coefficients.last().unwrap();
-            let rem_highest_term_degree = remainder.degree;
+        for idx in (0..quotient.len()).rev() {
+            let highest_term = idx + denominator.degree;
+            let res = C::div(&remainder[highest_term], divider_highest_term)
+                .ok_or(Error::NotInvertible)?;
 
-            let res = divider_highest_term * rem_highest_term;
-            let res_degree = rem_highest_term_degree - divider_highest_term_degree;
+            for (j, c) in denominator.coefficients.iter().enumerate() {
+                remainder[idx + j] = C::sub(&remainder[idx + j], &C::mul(&res, c));
+            }
+            quotient[idx] = res;
 
-            // Update the quotient's current term
-            let idx = res_degree;
-            quotient[idx] = quotient[idx] + res;
+            // The highest term cancels out by construction: don't leave rounding errors
+            // behind, they would be taken for a non-zero coefficient.
+            remainder[highest_term] = C::zero();
+        }
 
-            // Multiply `res` by the denominator
-            let mut res_poly = Polynomial::new(vec![Complex::ZERO; res_degree + 1]);
-            res_poly.coefficients[res_degree] = res;
+        remainder.truncate(denominator.degree.max(1));
 
-            let mul_res = Polynomial::mul(&res_poly, denominator);
-            let neg_res = mul_res.neg();
+        Ok((
+            Polynomial::new(quotient).trim(),
+            Polynomial::new(remainder).trim(),
+        ))
+    }
+
+    /// Returns the polynomial of degree below `points.len()` whose value at every `x` of the
+    /// `(x, y)` points is `y`.
+    ///
+    /// Panics if two points share their `x`, see [`Polynomial::try_interpolate`].
+    pub fn interpolate(points: &[(C, C)]) -> Polynomial<C> {
+        Self::try_interpolate(points).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-            remainder = Polynomial::add(&remainder, &neg_res);
+    /// Returns the polynomial of degree below `points.len()` whose value at every `x` of the
+    /// `(x, y)` points is `y`, with Lagrange's formula, or an error if two points share their
+    /// `x` or if the differences between them aren't invertible.
+    ///
+    /// With `M` the product of all the `x - x_j`, the basis polynomial of `x_i`, one at
+    /// `x_i` and zero at the other points, is `M / (x - x_i)` divided by `M'(x_i)`.
+    pub fn try_interpolate(points: &[(C, C)]) -> Result<Polynomial<C>, Error> {
+        for (second, (x, _)) in points.iter().enumerate() {
+            if let Some(first) = points[..second].iter().position(|(other, _)| other == x) {
+                return Err(Error::DuplicatePoint { first, second });
+            }
         }
 
-        let quotient = Polynomial::new(quotient);
+        let root = |x: &C| Polynomial::new(vec![x.neg(), C::one()]);
+        let product = points.iter().fold(Polynomial::one(), |product, (x, _)| {
+            Self::mul(&product, &root(x))
+        });
+        let derivative = product.derivative();
 
-        (quotient, remainder)
+        points
+            .iter()
+            .try_fold(Polynomial::zero(), |result, (x, y)| {
+                let (basis, _) = Self::try_euclidean_division(&product, &root(x))?;
+                let scale = C::div(y, &derivative.eval(x.clone())).ok_or(Error::NotInvertible)?;
+
+                Ok(Self::add(&result, &basis.map(|c| C::mul(c, &scale))))
+            })
     }
 
-    pub fn random(rand: &mut ThreadRng) -> Polynomial {
-        let degree = rand.gen_range(1..4);
+    /// Returns the monic greatest common divisor of two polynomials, or zero if both are
+    /// zero.
+    ///
+    /// See [`Polynomial::extended_gcd`] for how rounding errors are handled.
+    pub fn gcd(a: &Polynomial<C>, b: &Polynomial<C>) -> Polynomial<C> {
+        Self::extended_gcd(a, b).0
+    }
 
-        Polynomial::new(
-            (0..=degree)
-                .into_iter()
-                .map(|i| {
-                    if rand.gen_bool(0.7) || i == degree {
-                        Complex::random(rand)
-                    } else {
-                        Complex::ZERO
-                    }
-                })
-                .collect(),
+    /// Returns `(g, s, t)` such that `s a + t b = g`, with `g` the monic greatest common
+    /// divisor of `a` and `b`, computed with the extended Euclidean algorithm. When both are
+    /// zero, `g` is zero too.
+    ///
+    /// With float coefficients, remainders rarely vanish exactly: their coefficients whose
+    /// [`Field::magnitude`] is at most [`GCD_TOLERANCE`] times the largest one among the
+    /// coefficients of `a` and `b` are taken for zero. Exact coefficients are only flushed
+    /// when they're zero.
+    ///
+    /// Panics if a remainder has a leading coefficient without an inverse, which can only
+    /// happen when `C` isn't a field.
+    pub fn extended_gcd(
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+    ) -> (Polynomial<C>, Polynomial<C>, Polynomial<C>) {
+        let threshold = a.largest_magnitude().max(b.largest_magnitude()) * GCD_TOLERANCE;
+
+        let (mut r0, mut r1) = (a.clone().flush(threshold), b.clone().flush(threshold));
+        let (mut s0, mut s1) = (Polynomial::one(), Polynomial::zero());
+        let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::one());
+
+        while !r1.is_zero() {
+            let (q, r) = Self::euclidean_division(&r0, &r1);
+
+            let s2 = Self::sub(&s0, &Self::mul(&q, &s1));
+            let t2 = Self::sub(&t0, &Self::mul(&q, &t1));
+
+            (r0, r1) = (r1, r.flush(threshold));
+            (s0, s1) = (s1, s2);
+            (t0, t1) = (t1, t2);
+        }
+
+        if r0.is_zero() {
+            return (r0, Polynomial::one(), Polynomial::zero());
+        }
+
+        let leading_inverse = r0.coefficients[r0.degree]
+            .inv()
+            .unwrap_or_else(|| panic!("{}", Error::NotInvertible));
+        let normalize = Polynomial::new(vec![leading_inverse]);
+
+        (
+            Self::mul(&r0, &normalize),
+            Self::mul(&s0, &normalize),
+            Self::mul(&t0, &normalize),
         )
     }
+
+    /// Returns the largest magnitude among the coefficients of the polynomial.
+    pub(crate) fn largest_magnitude(&self) -> f32 {
+        self.coefficients
+            .iter()
+            .map(Field::magnitude)
+            .fold(0.0, f32::max)
+    }
+
+    /// Set the coefficients whose magnitude is at most `threshold` to zero, and trim the
+    /// polynomial.
+    pub(crate) fn flush(mut self, threshold: f32) -> Polynomial<C> {
+        for c in self.coefficients.iter_mut() {
+            if c.magnitude() <= threshold {
+                *c = C::zero();
+            }
+        }
+
+        self.trim()
+    }
+}
+
+impl Polynomial {
+    /// Returns the Euclidean norm of the coefficients of the polynomial.
+    pub fn norm(&self) -> f32 {
+        self.coefficients
+            .iter()
+            .map(|c| c.re * c.re + c.im * c.im)
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    /// Returns the largest modulus among the coefficients of the polynomial.
+    pub fn infinity_norm(&self) -> f32 {
+        self.coefficients
+            .iter()
+            .map(Complex::norm)
+            .fold(0.0, f32::max)
+    }
+
+    /// Conjugates all the coefficients of a polynomial.
+    pub fn conj(&self) -> Polynomial {
+        let mut result = self.clone();
+
+        result.coefficients.iter_mut().for_each(|c| {
+            *c = c.conj();
+        });
+
+        result
+    }
+
+    /// Evaluate the polynomial at the square matrix `a`, with Horner's method.
+    ///
+    /// Panics if `a` isn't square, see [`Polynomial::try_eval_matrix`].
+    pub fn eval_matrix(&self, a: &Matrix<Complex>) -> Matrix<Complex> {
+        self.try_eval_matrix(a)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Evaluate the polynomial at the matrix `a` with Horner's method, or return an error if
+    /// `a` isn't square. Constant terms are multiplied by the identity matrix.
+    pub fn try_eval_matrix(&self, a: &Matrix<Complex>) -> Result<Matrix<Complex>, Error> {
+        if a.width != a.height {
+            return Err(Error::NotSquare((a.width, a.height)));
+        }
+
+        let n = a.width;
+        let mut result = Matrix::new(vec![Complex::ZERO; n * n], n, n);
+
+        for (i, c) in self.coefficients.iter().rev().enumerate() {
+            if i != 0 {
+                result = Matrix::mul(&result, a);
+            }
+
+            for k in 0..n {
+                result.content[k * (n + 1)] = result.content[k * (n + 1)] + *c;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the inverse of the polynomial in the ring of the provided degree, such that
+    /// `mul_in_ring(self, inverse, ring)` is 1.
+    ///
+    /// Panics if there is no inverse or if `ring` is 0, see [`Polynomial::try_inverse_in_ring`].
+    pub fn inverse_in_ring(&self, ring: usize) -> Polynomial {
+        self.try_inverse_in_ring(ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the inverse of the polynomial in the ring of the provided degree, or an error
+    /// if `ring` is 0 or if the polynomial isn't invertible.
+    ///
+    /// Modulo `x^ring - 1`, a polynomial is invertible when it doesn't vanish on any of the
+    /// `ring`-th roots of unity. Its values there are its DFT, so the inverse is the inverse DFT
+    /// of the inverted values. Values smaller than `1e-5` times the largest one are taken
+    /// for zeros.
+    pub fn try_inverse_in_ring(&self, ring: usize) -> Result<Polynomial, Error> {
+        let mut coefficients = self.try_reduce_to(ring)?.coefficients;
+        coefficients.resize(ring, Complex::ZERO);
+
+        let values = dft::dft(&coefficients);
+        let largest = values.iter().map(Complex::norm).fold(0.0, f32::max);
+
+        if values.iter().any(|v| v.norm() <= largest * 1e-5) {
+            return Err(Error::NotInvertible);
+        }
+
+        let one = Complex::new(1.0, 0.0);
+        let inverses: Vec<Complex> = values.iter().map(|v| one / *v).collect();
+
+        Ok(Polynomial::new(dft::inverse_dft(&inverses)).trim())
+    }
+
+    /// Generate a polynomial of degree 1 to 3, where each coefficient below the highest one
+    /// is zero with a 30% probability.
+    ///
+    /// Use a [`crate::random::SeededRng`] to get the same polynomials on every platform, and
+    /// a [`RandomPolynomialConfig`] to control the degrees and the coefficients.
+    pub fn random<R: Rng + ?Sized>(rand: &mut R) -> Polynomial {
+        RandomPolynomialConfig::default().generate(rand)
+    }
 }
 
 #[cfg(feature = "parse")]
-impl From<&str> for Polynomial {
-    fn from(value: &str) -> Self {
+impl Polynomial {
+    /// Parse a polynomial such as `(1.23 + 3.45i)X2 + (-2.0 - 1.0i)X + (-1.0 + 0.0i)`,
+    /// returning an error if no term can be read from `text`.
+    pub fn try_parse(text: &str) -> Result<Polynomial, Error> {
         let mut complexes: Vec<(usize, Complex)> = vec![];
         let re = Regex::new(r#"\((?P<re_sign>[+-]?)(?P<re_value>([0-9]*[.])?[0-9]+) (?P<im_sign>[-+]) (?P<im_value>([0-9]*[.])?[0-9]+)?i\)(X(?P<coefficient>\d*))?"#).unwrap();
-        let names_iter = re.captures_iter(value);
+        let names_iter = re.captures_iter(text);
 
         for names in names_iter {
             let re_sign = names
@@ -262,13 +733,13 @@ impl From<&str> for Polynomial {
             let im_positive = im_sign == "+";
             let re_value = re_value
                 .parse::<f32>()
-                .expect("invalid float for real value");
+                .map_err(|_| Error::ParseError(format!("invalid real value: {re_value}")))?;
             let im_value = im_value
                 .parse::<f32>()
-                .expect("invalid float for imaginary value");
+                .map_err(|_| Error::ParseError(format!("invalid imaginary value: {im_value}")))?;
             let coefficient = coefficient
                 .parse::<usize>()
-                .expect("invalid positive integer for coefficient");
+                .map_err(|_| Error::ParseError(format!("invalid degree: {coefficient}")))?;
 
             let re = match re_positive {
                 true => re_value,
@@ -282,7 +753,10 @@ impl From<&str> for Polynomial {
         }
 
         complexes.sort_unstable_by(|(d1, _), (d2, _)| d1.partial_cmp(d2).unwrap());
-        let biggest_degree = complexes.last().unwrap().0;
+        let biggest_degree = match complexes.last() {
+            Some((degree, _)) => *degree,
+            None => return Err(Error::ParseError("no term found".to_string())),
+        };
 
         let mut coefficients = vec![Complex::ZERO; biggest_degree + 1];
 
@@ -297,14 +771,32 @@ impl From<&str> for Polynomial {
             }
         }
 
-        Polynomial::new(coefficients)
+        Ok(Polynomial::new(coefficients))
     }
 }
 
-impl Display for Polynomial {
+#[cfg(feature = "parse")]
+impl FromStr for Polynomial {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Polynomial::try_parse(s)
+    }
+}
+
+/// Panics if no term can be read from `value`, see [`Polynomial::try_parse`].
+#[cfg(feature = "parse")]
+impl From<&str> for Polynomial {
+    fn from(value: &str) -> Self {
+        Polynomial::try_parse(value).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl<C: Element + Display> Display for Polynomial<C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let zero = C::zero();
         for (i, coefficient) in self.coefficients.iter().rev().enumerate() {
-            if coefficient == &Complex::ZERO {
+            if coefficient == &zero {
                 continue;
             }
 
@@ -326,6 +818,7 @@ impl Display for Polynomial {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modular::Zq;
 
     #[test]
     fn test_polynomials_construction() {
@@ -336,6 +829,28 @@ mod tests {
         assert_eq!(1, p.degree);
     }
 
+    #[test]
+    fn test_trim_with() {
+        let p = Polynomial::new(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(1e-3, 0.0),
+            Complex::new(0.0, 1e-7),
+            Complex::ZERO,
+        ]);
+
+        assert_eq!(2, p.clone().trim().degree);
+        assert_eq!(1, p.clone().trim_with(1e-6).degree);
+        assert_eq!(0, p.clone().trim_with(1e-2).degree);
+        assert_eq!(
+            Polynomial::new(vec![Complex::new(1.0, 0.0)]),
+            p.trim_with(1.0)
+        );
+
+        // Exact coefficients only drop zeros, whatever the tolerance.
+        let q = Polynomial::new(vec![Zq::<7>::new(1), Zq::new(3), Zq::ZERO]);
+        assert_eq!(1, q.trim_with(0.5).degree);
+    }
+
     #[test]
     fn test_polynomials_add() {
         let p1 = Polynomial::new(vec![Complex::new(3.0, 0.0), Complex::new(1.0, 0.0)]);
@@ -479,6 +994,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reduce_negacyclic() {
+        let p = Polynomial::new(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(-1.0, 0.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(1.0, 0.0),
+        ]);
+
+        // 1 - x^2 + 2x^4 + x^5 with x^4 = -1
+        assert_eq!(
+            Polynomial::new(vec![
+                Complex::new(-1.0, 0.0),
+                Complex::new(-1.0, 0.0),
+                Complex::new(-1.0, 0.0),
+            ]),
+            p.reduce_negacyclic_to(4),
+        );
+        // and with x^2 = -1
+        assert_eq!(
+            Polynomial::new(vec![Complex::new(4.0, 0.0), Complex::new(1.0, 0.0)]),
+            p.reduce_negacyclic_to(2),
+        );
+    }
+
     #[test]
     fn test_polynomials_mul() {
         let p1 = Polynomial::new(vec![Complex::new(3.0, 0.0), Complex::new(1.0, 0.0)]);
@@ -521,4 +1063,232 @@ mod tests {
         let res_ring1 = Polynomial::zero();
         assert_eq!(res_ring1, Polynomial::mul_in_ring(&p1, &p2, 1));
     }
+
+    #[test]
+    fn test_mul_accumulate() {
+        let p1 = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 1.0)]);
+        let p2 = Polynomial::new(vec![
+            Complex::new(-1.0, 0.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(3.0, 0.0),
+        ]);
+
+        let mut acc = Polynomial::zero();
+        acc.mul_accumulate(&p1, &p2);
+        acc.mul_accumulate(&p2, &p1);
+        assert_eq!(
+            Polynomial::add(&Polynomial::mul(&p1, &p2), &Polynomial::mul(&p2, &p1)),
+            acc.trim()
+        );
+
+        let mut acc = Polynomial::zero();
+        acc.mul_accumulate_in_ring(&p1, &p2, 2);
+        assert_eq!(Polynomial::mul_in_ring(&p1, &p2, 2), acc.trim());
+    }
+
+    #[test]
+    fn test_errors() {
+        let p = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+
+        assert_eq!(Err(Error::InvalidRing(0)), p.try_reduce_to(0));
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Polynomial::try_add_in_ring(&p, &p, 0)
+        );
+        assert_eq!(
+            Err(Error::InvalidRing(0)),
+            Polynomial::try_mul_in_ring(&p, &p, 0)
+        );
+        assert_eq!(Ok(p.reduce_to(1)), p.try_reduce_to(1));
+
+        assert_eq!(
+            Err(Error::DivisionByZero),
+            Polynomial::try_euclidean_division(&p, &Polynomial::zero())
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok(Polynomial::new(vec![
+                Complex::new(-1.0, -0.5),
+                Complex::ZERO,
+                Complex::new(1.5, 2.0),
+            ])),
+            "(1.5 + 2i)X2 + (-1 - 0.5i)".parse()
+        );
+
+        assert_eq!(
+            Err(Error::ParseError("no term found".to_string())),
+            Polynomial::try_parse("X + 1")
+        );
+    }
+
+    #[test]
+    fn test_euclidean_division_non_monic() {
+        // (2x2 + 3x + 1) / (2x + 1) = x + 1
+        let (q, r) = Polynomial::euclidean_division(
+            &Polynomial::new(vec![
+                Complex::new(1.0, 0.0),
+                Complex::new(3.0, 0.0),
+                Complex::new(2.0, 0.0),
+            ]),
+            &Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]),
+        );
+        assert_eq!(
+            Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]),
+            q
+        );
+        assert_eq!(Polynomial::zero(), r);
+
+        // (x2 + 1) / 2ix = -0.5ix, remainder 1
+        let (q, r) = Polynomial::euclidean_division(
+            &Polynomial::new(vec![
+                Complex::new(1.0, 0.0),
+                Complex::ZERO,
+                Complex::new(1.0, 0.0),
+            ]),
+            &Polynomial::new(vec![Complex::ZERO, Complex::new(0.0, 2.0)]),
+        );
+        assert_eq!(
+            Polynomial::new(vec![Complex::ZERO, Complex::new(0.0, -0.5)]),
+            q
+        );
+        assert_eq!(Polynomial::new(vec![Complex::new(1.0, 0.0)]), r);
+
+        // A numerator of lower degree is the remainder.
+        let one = Polynomial::new(vec![Complex::new(1.0, 0.0)]);
+        let x = Polynomial::new(vec![Complex::ZERO, Complex::new(1.0, 0.0)]);
+        assert_eq!(
+            (Polynomial::zero(), one.clone()),
+            Polynomial::euclidean_division(&one, &x)
+        );
+    }
+
+    #[test]
+    fn test_gcd() {
+        let real = |coefficients: &[f32]| {
+            Polynomial::new(
+                coefficients
+                    .iter()
+                    .map(|&re| Complex::new(re, 0.0))
+                    .collect(),
+            )
+        };
+
+        // (x - 1)(x + 2) and 3(x - 1)(x - 3) share x - 1
+        let a = real(&[-2.0, 1.0, 1.0]);
+        let b = real(&[9.0, -12.0, 3.0]);
+        let (g, s, t) = Polynomial::extended_gcd(&a, &b);
+
+        assert_eq!(real(&[-1.0, 1.0]), g);
+        let combination = Polynomial::add(&Polynomial::mul(&s, &a), &Polynomial::mul(&t, &b));
+        assert!(Polynomial::sub(&combination, &g).infinity_norm() < 1e-5);
+
+        // Coprime polynomials have a constant GCD, and zero is divisible by anything.
+        assert_eq!(Polynomial::one(), Polynomial::gcd(&a, &real(&[1.0, 1.0])));
+        assert_eq!(
+            real(&[0.5, 1.0]),
+            Polynomial::gcd(&Polynomial::zero(), &real(&[1.0, 2.0]))
+        );
+        let zero: Polynomial = Polynomial::zero();
+        assert_eq!(zero, Polynomial::gcd(&zero, &zero));
+    }
+
+    #[test]
+    fn test_interpolate() {
+        type Z97 = Zq<97>;
+        let p = Polynomial::new(vec![Z97::new(5), Z97::new(-3), Z97::new(0), Z97::new(7)]);
+
+        // Four points determine the cubic, more give it back too.
+        let points: Vec<_> = [2, 10, 50, 96, 0]
+            .into_iter()
+            .map(|x| (Z97::new(x), p.eval(Z97::new(x))))
+            .collect();
+        assert_eq!(p, Polynomial::interpolate(&points[..4]));
+        assert_eq!(p, Polynomial::interpolate(&points));
+
+        // Fewer points give the polynomial of lowest degree through them.
+        let line = Polynomial::interpolate(&points[..2]);
+        assert_eq!(1, line.degree);
+        assert_eq!(points[1].1, line.eval(points[1].0));
+
+        assert_eq!(Polynomial::zero(), Polynomial::<Z97>::interpolate(&[]));
+        assert_eq!(
+            Err(Error::DuplicatePoint {
+                first: 0,
+                second: 2
+            }),
+            Polynomial::try_interpolate(&[points[0], points[1], points[0]])
+        );
+    }
+
+    #[test]
+    fn test_inverse_in_ring() {
+        let one = Polynomial::new(vec![Complex::new(1.0, 0.0)]);
+
+        // 2 + x in C[x]/(x3 - 1)
+        let p = Polynomial::new(vec![Complex::new(2.0, 0.0), Complex::new(1.0, 0.0)]);
+        let inverse = p.inverse_in_ring(3);
+        let product = Polynomial::mul_in_ring(&p, &inverse, 3);
+
+        // 1 / (2 + x) = (4 - 2x + x2) / 9
+        for (c, expected) in inverse.coefficients.iter().zip([4.0, -2.0, 1.0]) {
+            assert!((*c - Complex::new(expected / 9.0, 0.0)).norm() < 1e-5);
+        }
+        for (i, c) in product.coefficients.iter().enumerate() {
+            let expected = if i == 0 { 1.0 } else { 0.0 };
+            assert!((*c - Complex::new(expected, 0.0)).norm() < 1e-5);
+        }
+
+        assert_eq!(one.clone(), one.inverse_in_ring(4));
+
+        // 1 - x vanishes at 1, which is a root of x^3 - 1
+        let p = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(-1.0, 0.0)]);
+        assert_eq!(Err(Error::NotInvertible), p.try_inverse_in_ring(3));
+    }
+
+    #[test]
+    fn test_eval() {
+        // 1 + 2x + x2 = (1 + x)2
+        let p = Polynomial::new(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(1.0, 0.0),
+        ]);
+
+        assert_eq!(Complex::new(9.0, 0.0), p.eval(Complex::new(2.0, 0.0)));
+        assert_eq!(Complex::new(0.0, 2.0), p.eval(Complex::new(0.0, 1.0)));
+        assert_eq!(
+            Polynomial::new(vec![Complex::new(2.0, 0.0), Complex::new(2.0, 0.0)]),
+            p.derivative()
+        );
+        assert!(p.derivative().derivative().derivative().is_zero());
+
+        // (I + A)2 with A = [[0, 1], [0, 0]] is [[1, 2], [0, 1]]
+        let a = Matrix::new(
+            vec![
+                Complex::ZERO,
+                Complex::new(1.0, 0.0),
+                Complex::ZERO,
+                Complex::ZERO,
+            ],
+            2,
+            2,
+        );
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    Complex::new(1.0, 0.0),
+                    Complex::new(2.0, 0.0),
+                    Complex::ZERO,
+                    Complex::new(1.0, 0.0),
+                ],
+                2,
+                2,
+            ),
+            p.eval_matrix(&a)
+        );
+    }
 }
@@ -0,0 +1,464 @@
+use std::{f64::consts::TAU, ops::RangeInclusive};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{complex::Complex, matrix::Matrix, polynomial::Polynomial};
+
+/// The random number generator to use when generated values need to be reproducible.
+///
+/// ChaCha8 has a portable output: the same seed yields the same polynomials on every platform,
+/// unlike `ThreadRng` or `StdRng` whose algorithm may change between `rand` releases.
+pub type SeededRng = ChaCha8Rng;
+
+/// Create a [`SeededRng`] from a 64-bit seed.
+pub fn seeded(seed: u64) -> SeededRng {
+    SeededRng::seed_from_u64(seed)
+}
+
+/// The distribution of the non-zero coefficients of randomly generated polynomials.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoefficientDistribution {
+    /// Real and imaginary parts uniformly distributed in `[-bound, bound)`.
+    UniformBox { bound: f32 },
+    /// Uniformly distributed in the disk of radius `radius` centered on 0.
+    UniformDisk { radius: f32 },
+    /// Real and imaginary parts independently drawn from a normal distribution
+    /// centered on 0 with the standard deviation `sigma`.
+    Gaussian { sigma: f32 },
+    /// Real integers drawn from a discrete Gaussian centered on 0 with the parameter `sigma`,
+    /// such as the small errors of lattice-based schemes. Samples are cut at `6 * sigma`.
+    DiscreteGaussian { sigma: f32 },
+    /// Real integers uniformly distributed in `[-bound, bound]`.
+    Integer { bound: i32 },
+    /// Uniformly distributed on the unit circle.
+    UnitModulus,
+}
+
+impl CoefficientDistribution {
+    /// Draw a single coefficient from the distribution.
+    ///
+    /// Panics if the parameter of the distribution isn't positive, since it couldn't draw
+    /// anything but zero.
+    pub fn sample<R: Rng + ?Sized>(&self, rand: &mut R) -> Complex {
+        self.check();
+
+        match *self {
+            CoefficientDistribution::UniformBox { bound } => Complex {
+                re: rand.gen_range(-bound..bound),
+                im: rand.gen_range(-bound..bound),
+            },
+            CoefficientDistribution::UniformDisk { radius } => loop {
+                // Rejection sampling from the enclosing square keeps the density uniform.
+                let re = rand.gen_range(-radius..=radius);
+                let im = rand.gen_range(-radius..=radius);
+
+                if re * re + im * im <= radius * radius {
+                    break Complex::new(re, im);
+                }
+            },
+            CoefficientDistribution::Gaussian { sigma } => {
+                let (re, im) = standard_normal_pair(rand);
+
+                Complex::new(re as f32 * sigma, im as f32 * sigma)
+            }
+            CoefficientDistribution::DiscreteGaussian { sigma } => {
+                Complex::new(discrete_gaussian(rand, sigma) as f32, 0.0)
+            }
+            CoefficientDistribution::Integer { bound } => {
+                Complex::new(rand.gen_range(-bound..=bound) as f32, 0.0)
+            }
+            CoefficientDistribution::UnitModulus => {
+                let theta: f64 = rand.gen_range(0.0..TAU);
+
+                Complex::new(theta.cos() as f32, theta.sin() as f32)
+            }
+        }
+    }
+
+    /// Draw a coefficient from the distribution conditioned on being non-zero, for the
+    /// leading coefficients of polynomials.
+    ///
+    /// Panics like [`CoefficientDistribution::sample`].
+    pub fn sample_non_zero<R: Rng + ?Sized>(&self, rand: &mut R) -> Complex {
+        self.check();
+
+        let sign = |rand: &mut R| if rand.gen() { 1 } else { -1 };
+        match *self {
+            // Integer distributions draw zero too often, and maybe almost always, for
+            // rejecting it: draw a magnitude that can't be zero instead.
+            CoefficientDistribution::DiscreteGaussian { sigma } => {
+                let x = discrete_gaussian_non_zero(rand, sigma) * sign(rand);
+
+                Complex::new(x as f32, 0.0)
+            }
+            CoefficientDistribution::Integer { bound } => {
+                let x = rand.gen_range(1..=bound) * sign(rand);
+
+                Complex::new(x as f32, 0.0)
+            }
+            _ => loop {
+                let c = self.sample(rand);
+
+                if c != Complex::ZERO {
+                    break c;
+                }
+            },
+        }
+    }
+
+    /// Panics with an explanation if the parameter of the distribution isn't positive.
+    fn check(&self) {
+        let (parameter, positive) = match *self {
+            CoefficientDistribution::UniformBox { bound } => ("bound", bound > 0.0),
+            CoefficientDistribution::UniformDisk { radius } => ("radius", radius > 0.0),
+            CoefficientDistribution::Gaussian { sigma }
+            | CoefficientDistribution::DiscreteGaussian { sigma } => ("sigma", sigma > 0.0),
+            CoefficientDistribution::Integer { bound } => ("bound", bound > 0),
+            CoefficientDistribution::UnitModulus => return,
+        };
+
+        assert!(
+            positive,
+            "the {parameter} of {self:?} must be positive to draw non-zero coefficients"
+        );
+    }
+}
+
+/// The magnitude of an integer drawn from a discrete Gaussian of parameter `sigma`,
+/// conditioned on being non-zero, by rejection sampling relative to the density at 1, which
+/// is always accepted however small `sigma` is.
+fn discrete_gaussian_non_zero<R: Rng + ?Sized>(rand: &mut R, sigma: f32) -> i32 {
+    let sigma = sigma as f64;
+    let tail = ((6.0 * sigma).ceil() as i32).max(1);
+
+    loop {
+        let x = rand.gen_range(1..=tail);
+        let p = (-((x as f64).powi(2) - 1.0) / (2.0 * sigma * sigma)).exp();
+
+        if rand.gen_bool(p) {
+            return x;
+        }
+    }
+}
+
+/// Two independent standard normal samples, using the Box-Muller transform.
+fn standard_normal_pair<R: Rng + ?Sized>(rand: &mut R) -> (f64, f64) {
+    // `gen::<f64>()` is in [0, 1), so `1 - u` is never 0 and its logarithm is finite.
+    let u1: f64 = 1.0 - rand.gen::<f64>();
+    let u2: f64 = rand.gen();
+
+    let radius = (-2.0 * u1.ln()).sqrt();
+    let theta = TAU * u2;
+
+    (radius * theta.cos(), radius * theta.sin())
+}
+
+/// An integer drawn from a discrete Gaussian of parameter `sigma`, by rejection sampling.
+fn discrete_gaussian<R: Rng + ?Sized>(rand: &mut R, sigma: f32) -> i32 {
+    let sigma = sigma as f64;
+    let tail = (6.0 * sigma).ceil() as i32;
+
+    loop {
+        let x = rand.gen_range(-tail..=tail);
+        let p = (-(x as f64).powi(2) / (2.0 * sigma * sigma)).exp();
+
+        if rand.gen_bool(p) {
+            return x;
+        }
+    }
+}
+
+/// Parameters of random polynomial generation, built with chained setters:
+///
+/// ```
+/// use libcomplex_polynomials::random::{self, CoefficientDistribution, RandomPolynomialConfig};
+///
+/// let errors = RandomPolynomialConfig::new()
+///     .ring(256)
+///     .sparsity(0.0)
+///     .distribution(CoefficientDistribution::DiscreteGaussian { sigma: 3.2 });
+///
+/// let p = errors.generate(&mut random::seeded(0));
+/// assert!(p.degree < 256);
+/// ```
+///
+/// The default configuration is the one used by [`Polynomial::random`]: degrees 1 to 3,
+/// a 30% sparsity and coefficients uniform in `[-10, 10)` for both parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomPolynomialConfig {
+    degrees: RangeInclusive<usize>,
+    sparsity: f64,
+    distribution: CoefficientDistribution,
+    monic: bool,
+    ring: Option<usize>,
+}
+
+impl Default for RandomPolynomialConfig {
+    fn default() -> Self {
+        RandomPolynomialConfig {
+            degrees: 1..=3,
+            sparsity: 0.3,
+            distribution: CoefficientDistribution::UniformBox { bound: 10.0 },
+            monic: false,
+            ring: None,
+        }
+    }
+}
+
+impl RandomPolynomialConfig {
+    pub fn new() -> RandomPolynomialConfig {
+        RandomPolynomialConfig::default()
+    }
+
+    /// Draw the degree of generated polynomials uniformly from `degrees`.
+    pub fn degrees(mut self, degrees: RangeInclusive<usize>) -> RandomPolynomialConfig {
+        assert!(!degrees.is_empty(), "empty degree range");
+
+        self.degrees = degrees;
+        self
+    }
+
+    /// Set the probability of each coefficient below the highest one to be zero.
+    pub fn sparsity(mut self, sparsity: f64) -> RandomPolynomialConfig {
+        assert!(
+            (0.0..=1.0).contains(&sparsity),
+            "sparsity must be a probability"
+        );
+
+        self.sparsity = sparsity;
+        self
+    }
+
+    /// Set the distribution the non-zero coefficients are drawn from.
+    ///
+    /// Panics if its parameter isn't positive, see [`CoefficientDistribution::sample`].
+    pub fn distribution(mut self, distribution: CoefficientDistribution) -> RandomPolynomialConfig {
+        distribution.check();
+
+        self.distribution = distribution;
+        self
+    }
+
+    /// Make the highest degree coefficient of generated polynomials 1.
+    pub fn monic(mut self, monic: bool) -> RandomPolynomialConfig {
+        self.monic = monic;
+        self
+    }
+
+    /// Generate polynomials that are already reduced to the provided ring degree, see
+    /// [`Polynomial::reduce_to`]. Degrees above `ring - 1` are clamped to `ring - 1`, rather
+    /// than reduced, so that coefficients keep their distribution.
+    pub fn ring(mut self, ring: usize) -> RandomPolynomialConfig {
+        assert!(ring > 0, "ring degree must be positive");
+
+        self.ring = Some(ring);
+        self
+    }
+
+    /// Generate a polynomial following this configuration.
+    pub fn generate<R: Rng + ?Sized>(&self, rand: &mut R) -> Polynomial {
+        let (mut low, mut high) = (*self.degrees.start(), *self.degrees.end());
+        if let Some(ring) = self.ring {
+            low = low.min(ring - 1);
+            high = high.min(ring - 1);
+        }
+
+        // Sampling a `u32` rather than a `usize` keeps the output the same on 32-bit targets.
+        let degree = rand.gen_range(low as u32..=high as u32) as usize;
+
+        Polynomial::new(
+            (0..=degree)
+                .map(|i| {
+                    if i == degree && self.monic {
+                        Complex::new(1.0, 0.0)
+                    } else if rand.gen_bool(1.0 - self.sparsity) || i == degree {
+                        // A zero leading coefficient would lower the drawn degree.
+                        if i == degree {
+                            self.distribution.sample_non_zero(rand)
+                        } else {
+                            self.distribution.sample(rand)
+                        }
+                    } else {
+                        Complex::ZERO
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Parameters of random matrix generation: its size, and the configuration of its entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomMatrixConfig {
+    width: usize,
+    height: usize,
+    entries: RandomPolynomialConfig,
+}
+
+impl RandomMatrixConfig {
+    pub fn new(width: usize, height: usize) -> RandomMatrixConfig {
+        RandomMatrixConfig {
+            width,
+            height,
+            entries: RandomPolynomialConfig::default(),
+        }
+    }
+
+    /// Set the configuration every entry of generated matrices follows.
+    pub fn entries(mut self, entries: RandomPolynomialConfig) -> RandomMatrixConfig {
+        self.entries = entries;
+        self
+    }
+
+    /// Generate a matrix following this configuration, with entries generated in row-major order.
+    pub fn generate<R: Rng + ?Sized>(&self, rand: &mut R) -> Matrix {
+        let content = (0..self.width * self.height)
+            .map(|_| self.entries.generate(rand))
+            .collect();
+
+        Matrix::new(content, self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_values() {
+        let mut r1 = seeded(1234);
+        let mut r2 = seeded(1234);
+
+        for _ in 0..16 {
+            assert_eq!(Polynomial::random(&mut r1), Polynomial::random(&mut r2));
+        }
+
+        assert_eq!(
+            Matrix::random(3, 2, &mut seeded(5)),
+            Matrix::random(3, 2, &mut seeded(5))
+        );
+        assert_ne!(
+            Matrix::random(3, 2, &mut seeded(5)),
+            Matrix::random(3, 2, &mut seeded(6))
+        );
+    }
+
+    #[test]
+    fn test_known_values() {
+        // Guards the portability of seeded generation: changing the generator or the sampling
+        // order in `Polynomial::random` breaks the reproducibility promised to users.
+        assert_eq!(
+            Complex::new(3.0939407, 4.181507),
+            Complex::random(&mut seeded(0))
+        );
+        assert_eq!(
+            Polynomial::new(vec![
+                Complex::new(9.0055065, 5.4462643),
+                Complex::new(2.5472088, 4.74312),
+            ]),
+            Polynomial::random(&mut seeded(42))
+        );
+    }
+
+    #[test]
+    fn test_polynomial_config() {
+        let mut rand = seeded(3);
+
+        let config = RandomPolynomialConfig::new()
+            .degrees(4..=6)
+            .sparsity(0.0)
+            .monic(true)
+            .distribution(CoefficientDistribution::Integer { bound: 2 });
+
+        for _ in 0..32 {
+            let p = config.generate(&mut rand);
+
+            assert!((4..=6).contains(&p.degree));
+            assert_eq!(Complex::new(1.0, 0.0), p.coefficients[p.degree]);
+            for c in &p.coefficients {
+                assert!(c.re.fract() == 0.0 && c.re.abs() <= 2.0 && c.im == 0.0);
+            }
+        }
+
+        let config = RandomPolynomialConfig::new().degrees(5..=9).ring(4);
+        for _ in 0..32 {
+            let p = config.generate(&mut rand);
+
+            assert_eq!(3, p.degree);
+            assert_eq!(p, p.reduce_to(4));
+        }
+
+        let config = RandomPolynomialConfig::new().degrees(8..=8).sparsity(1.0);
+        let p = config.generate(&mut rand);
+        assert!(p.coefficients[..8].iter().all(|c| *c == Complex::ZERO));
+    }
+
+    #[test]
+    fn test_distributions() {
+        let mut rand = seeded(9);
+
+        for _ in 0..256 {
+            let c = CoefficientDistribution::UniformDisk { radius: 2.0 }.sample(&mut rand);
+            assert!(c.re * c.re + c.im * c.im <= 4.0);
+
+            let c = CoefficientDistribution::UnitModulus.sample(&mut rand);
+            assert!((c.re * c.re + c.im * c.im - 1.0).abs() < 1e-5);
+
+            let c = CoefficientDistribution::DiscreteGaussian { sigma: 1.5 }.sample(&mut rand);
+            assert!(c.re.fract() == 0.0 && c.re.abs() <= 9.0 && c.im == 0.0);
+        }
+
+        // The sample standard deviation of a Gaussian should be close to `sigma`.
+        let samples: Vec<Complex> = (0..4096)
+            .map(|_| CoefficientDistribution::Gaussian { sigma: 3.0 }.sample(&mut rand))
+            .collect();
+        let variance = samples.iter().map(|c| c.re * c.re).sum::<f32>() / samples.len() as f32;
+        assert!((variance.sqrt() - 3.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_non_zero_leading_coefficient() {
+        let mut rand = seeded(28);
+
+        // These distributions draw zero often, or almost always for the tiny `sigma`.
+        for distribution in [
+            CoefficientDistribution::Integer { bound: 1 },
+            CoefficientDistribution::DiscreteGaussian { sigma: 1.0 },
+            CoefficientDistribution::DiscreteGaussian { sigma: 0.01 },
+        ] {
+            let config = RandomPolynomialConfig::new()
+                .degrees(0..=4)
+                .distribution(distribution);
+
+            for _ in 0..64 {
+                let p = config.generate(&mut rand);
+                assert_ne!(Complex::ZERO, p.coefficients[p.degree]);
+                assert_eq!(p.clone().trim(), p);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the bound of Integer { bound: 0 } must be positive")]
+    fn test_integer_zero_bound() {
+        RandomPolynomialConfig::new().distribution(CoefficientDistribution::Integer { bound: 0 });
+    }
+
+    #[test]
+    #[should_panic(expected = "the bound of UniformBox { bound: 0.0 } must be positive")]
+    fn test_uniform_box_zero_bound() {
+        CoefficientDistribution::UniformBox { bound: 0.0 }.sample(&mut seeded(0));
+    }
+
+    #[test]
+    fn test_matrix_config() {
+        let config = RandomMatrixConfig::new(3, 2)
+            .entries(RandomPolynomialConfig::new().degrees(0..=0).monic(true));
+
+        assert_eq!(
+            Matrix::new(vec![Polynomial::new(vec![Complex::new(1.0, 0.0)]); 6], 3, 2),
+            config.generate(&mut seeded(0))
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Reed-Solomon codes over `GF(2^8)`, correcting up to `(n - k) / 2` corrupted bytes in
+//! every block of `n` bytes.
+//!
+//! A codeword of an `(n, k)` code is the polynomial whose coefficients are its `n` bytes,
+//! the first byte being the coefficient of the highest degree, and which is a multiple of
+//! the generator `g(x) = (x - 1)(x - α)...(x - α^(n - k - 1))`, `α` being the generator of
+//! [`Gf256`]. The encoding is systematic: a codeword is the `k` bytes of the message
+//! followed by the `n - k` bytes of the remainder of its division by `g`.
+//!
+//! Decoding evaluates the received word at the roots of `g` to get its syndromes, which are
+//! all zero for a codeword, finds the error locator polynomial with
+//! [`berlekamp_massey`], the positions of the errors as the inverses of its roots with
+//! [`chien_search`], and their values with [`forney`].
+
+use crate::{error::Error, finite_field::Gf256, polynomial::Polynomial};
+
+/// An `(n, k)` Reed-Solomon code, encoding blocks of `k` bytes into `n` bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReedSolomon {
+    n: usize,
+    k: usize,
+    generator: Polynomial<Gf256>,
+}
+
+impl ReedSolomon {
+    /// Create the `(n, k)` code.
+    ///
+    /// Panics if the parameters are out of range, see [`ReedSolomon::try_new`].
+    pub fn new(n: usize, k: usize) -> ReedSolomon {
+        Self::try_new(n, k).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create the `(n, k)` code, or returns an error unless `0 < k < n <= 255`. Codes
+    /// shorter than 255 bytes are shortened codes, whose missing leading bytes are zeros.
+    pub fn try_new(n: usize, k: usize) -> Result<ReedSolomon, Error> {
+        if k == 0 || k >= n || n > 255 {
+            return Err(Error::InvalidCode { n, k });
+        }
+
+        let generator = (0..n - k).fold(Polynomial::one(), |g, i| {
+            Polynomial::mul(&g, &Polynomial::new(vec![Gf256::exp(i), Gf256::ONE]))
+        });
+
+        Ok(ReedSolomon { n, k, generator })
+    }
+
+    /// The number of bytes of a codeword.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The number of bytes of a message.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The number of corrupted bytes a codeword can be corrected from, `(n - k) / 2`.
+    pub fn correctable(&self) -> usize {
+        (self.n - self.k) / 2
+    }
+
+    /// The generator polynomial, which divides all the codewords.
+    pub fn generator(&self) -> &Polynomial<Gf256> {
+        &self.generator
+    }
+
+    /// Returns the codeword of `message`, the message followed by `n - k` parity bytes.
+    ///
+    /// Panics if `message` isn't `k` bytes long, see [`ReedSolomon::try_encode`].
+    pub fn encode(&self, message: &[u8]) -> Vec<u8> {
+        self.try_encode(message)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the codeword of `message`, or an error if it isn't `k` bytes long.
+    pub fn try_encode(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        check_length(message, self.k)?;
+
+        // The message times `x^(n - k)`, minus its remainder modulo `g`, is a multiple of `g`
+        // whose highest coefficients are the message.
+        let parity = self.n - self.k;
+        let mut shifted = vec![Gf256::ZERO; parity];
+        shifted.extend(message.iter().rev().map(|&byte| Gf256::new(byte)));
+
+        let (_, remainder) =
+            Polynomial::try_euclidean_division(&Polynomial::new(shifted), &self.generator)?;
+
+        let mut codeword = message.to_vec();
+        codeword.extend(
+            (0..parity)
+                .rev()
+                .map(|i| coefficient(&remainder, i).value()),
+        );
+
+        Ok(codeword)
+    }
+
+    /// Returns the syndromes of a received word, its values at the `n - k` roots of the
+    /// generator, which are all zero if and only if it's a codeword.
+    ///
+    /// Panics if `received` isn't `n` bytes long, see [`ReedSolomon::try_syndromes`].
+    pub fn syndromes(&self, received: &[u8]) -> Vec<Gf256> {
+        self.try_syndromes(received)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the syndromes of a received word, or an error if it isn't `n` bytes long.
+    pub fn try_syndromes(&self, received: &[u8]) -> Result<Vec<Gf256>, Error> {
+        check_length(received, self.n)?;
+
+        let r = to_polynomial(received);
+
+        Ok((0..self.n - self.k)
+            .map(|i| r.eval(Gf256::exp(i)))
+            .collect())
+    }
+
+    /// Returns the codeword closest to `received`.
+    ///
+    /// Panics if it can't be corrected, see [`ReedSolomon::try_correct`].
+    pub fn correct(&self, received: &[u8]) -> Vec<u8> {
+        self.try_correct(received)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the codeword closest to `received`, or an error if `received` isn't `n` bytes
+    /// long or has more than [`ReedSolomon::correctable`] corrupted bytes.
+    ///
+    /// Beyond that many errors, the received word is either detected as uncorrectable or,
+    /// if it happens to be close enough to another codeword, silently miscorrected.
+    pub fn try_correct(&self, received: &[u8]) -> Result<Vec<u8>, Error> {
+        let syndromes = self.try_syndromes(received)?;
+        if syndromes.iter().all(|s| *s == Gf256::ZERO) {
+            return Ok(received.to_vec());
+        }
+
+        let locator = berlekamp_massey(&syndromes);
+        if locator.degree > self.correctable() {
+            return Err(Error::TooManyErrors);
+        }
+
+        // A locator with fewer roots than its degree among the positions of the codeword
+        // doesn't describe actual errors.
+        let positions = chien_search(&locator, self.n);
+        if positions.len() != locator.degree {
+            return Err(Error::TooManyErrors);
+        }
+
+        let mut corrected = received.to_vec();
+        for (position, magnitude) in positions
+            .iter()
+            .zip(forney(&syndromes, &locator, &positions))
+        {
+            corrected[self.n - 1 - position] ^= magnitude.value();
+        }
+
+        if self
+            .try_syndromes(&corrected)?
+            .iter()
+            .any(|s| *s != Gf256::ZERO)
+        {
+            return Err(Error::TooManyErrors);
+        }
+
+        Ok(corrected)
+    }
+
+    /// Returns the message of the codeword closest to `received`.
+    ///
+    /// Panics if it can't be corrected, see [`ReedSolomon::try_decode`].
+    pub fn decode(&self, received: &[u8]) -> Vec<u8> {
+        self.try_decode(received)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the message of the codeword closest to `received`, or an error if it can't be
+    /// corrected, see [`ReedSolomon::try_correct`].
+    pub fn try_decode(&self, received: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut codeword = self.try_correct(received)?;
+        codeword.truncate(self.k);
+
+        Ok(codeword)
+    }
+}
+
+/// Returns the error locator polynomial `Λ(x) = (1 - X_1 x)...(1 - X_v x)` of the shortest
+/// linear recurrence generating `syndromes`, with the Berlekamp-Massey algorithm. When there
+/// are at most `syndromes.len() / 2` errors, the `X_i` are `α^p` for their positions `p`.
+pub fn berlekamp_massey(syndromes: &[Gf256]) -> Polynomial<Gf256> {
+    let mut locator = Polynomial::one();
+    let mut previous = Polynomial::one();
+    let mut previous_discrepancy = Gf256::ONE;
+    let mut length = 0;
+    // The shift of `previous` since it was last updated.
+    let mut shift = 1;
+
+    for n in 0..syndromes.len() {
+        let discrepancy = (0..=length.min(locator.degree)).fold(Gf256::ZERO, |acc, i| {
+            acc + coefficient(&locator, i) * syndromes[n - i]
+        });
+
+        if discrepancy == Gf256::ZERO {
+            shift += 1;
+            continue;
+        }
+
+        // Cancel the discrepancy with the previous locator, shifted and scaled.
+        let scale = discrepancy * previous_discrepancy.inv().expect("non-zero discrepancy");
+        let mut correction = vec![Gf256::ZERO; shift];
+        correction.extend(previous.coefficients.iter().map(|c| *c * scale));
+        let updated = Polynomial::sub(&locator, &Polynomial::new(correction));
+
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = std::mem::replace(&mut locator, updated);
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = updated;
+            shift += 1;
+        }
+    }
+
+    locator
+}
+
+/// Returns the positions `p` in `[0, n)` such that `α^-p` is a root of `locator`, with
+/// Chien's search: the terms `Λ_j α^(-pj)` are updated from one position to the next with a
+/// single product each, instead of evaluating the polynomial from scratch.
+pub fn chien_search(locator: &Polynomial<Gf256>, n: usize) -> Vec<usize> {
+    let mut terms = locator.coefficients.clone();
+    let steps: Vec<_> = (0..terms.len())
+        .map(|j| Gf256::exp(255 - j % 255))
+        .collect();
+    let mut positions = vec![];
+
+    for p in 0..n {
+        if terms.iter().fold(Gf256::ZERO, |acc, term| acc + *term) == Gf256::ZERO {
+            positions.push(p);
+        }
+
+        for (term, step) in terms.iter_mut().zip(&steps) {
+            *term = *term * *step;
+        }
+    }
+
+    positions
+}
+
+/// Returns the values of the errors at `positions`, with Forney's formula: the error at
+/// position `p`, with `X = α^p`, is `X Ω(X^-1) / Λ'(X^-1)`, where `Ω = S Λ mod x^(n - k)`
+/// is the error evaluator polynomial and `S` the polynomial whose coefficients are the
+/// syndromes.
+pub fn forney(syndromes: &[Gf256], locator: &Polynomial<Gf256>, positions: &[usize]) -> Vec<Gf256> {
+    let mut evaluator = Polynomial::mul(&Polynomial::new(syndromes.to_vec()), locator);
+    evaluator.coefficients.truncate(syndromes.len());
+    let evaluator = Polynomial::new(evaluator.coefficients).trim();
+    let derivative = locator.derivative();
+
+    positions
+        .iter()
+        .map(|&p| {
+            let x = Gf256::exp(p);
+            let x_inverse = Gf256::exp(255 - p % 255);
+            let denominator = derivative.eval(x_inverse).inv().unwrap_or(Gf256::ZERO);
+
+            x * evaluator.eval(x_inverse) * denominator
+        })
+        .collect()
+}
+
+/// Returns the coefficient of degree `i` of `p`, zero past its degree.
+fn coefficient(p: &Polynomial<Gf256>, i: usize) -> Gf256 {
+    p.coefficients.get(i).copied().unwrap_or(Gf256::ZERO)
+}
+
+/// Returns the polynomial whose coefficients are `bytes`, the first one being the
+/// coefficient of the highest degree.
+fn to_polynomial(bytes: &[u8]) -> Polynomial<Gf256> {
+    Polynomial::new(bytes.iter().rev().map(|&byte| Gf256::new(byte)).collect())
+}
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<(), Error> {
+    if bytes.len() != expected {
+        return Err(Error::LengthMismatch {
+            expected,
+            found: bytes.len(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{seq::index, Rng};
+
+    use super::*;
+    use crate::random;
+
+    #[test]
+    fn test_encode() {
+        let code = ReedSolomon::new(255, 223);
+        assert_eq!(32, code.generator().degree);
+        assert_eq!(16, code.correctable());
+
+        // The generator of the (6, 4) code is (x - 1)(x - α) = x^2 + 3x + 2.
+        let code = ReedSolomon::new(6, 4);
+        assert_eq!(
+            &Polynomial::new(vec![Gf256::new(2), Gf256::new(3), Gf256::ONE]),
+            code.generator()
+        );
+
+        let message = b"Hello, world!";
+        let code = ReedSolomon::new(message.len() + 10, message.len());
+        let codeword = code.encode(message);
+
+        assert_eq!(&message[..], &codeword[..message.len()]);
+        assert!(code.syndromes(&codeword).iter().all(|s| *s == Gf256::ZERO));
+        let (_, remainder) =
+            Polynomial::euclidean_division(&to_polynomial(&codeword), code.generator());
+        assert!(remainder.is_zero());
+
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 13,
+                found: 3
+            }),
+            code.try_encode(b"abc")
+        );
+        assert_eq!(
+            Err(Error::InvalidCode { n: 256, k: 200 }),
+            ReedSolomon::try_new(256, 200)
+        );
+        assert_eq!(
+            Err(Error::InvalidCode { n: 10, k: 10 }),
+            ReedSolomon::try_new(10, 10)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut rand = random::seeded(47);
+
+        for (n, k) in [(255, 223), (20, 12), (15, 14), (255, 1)] {
+            let code = ReedSolomon::new(n, k);
+            let message: Vec<u8> = (0..k).map(|_| rand.gen()).collect();
+            let codeword = code.encode(&message);
+
+            for errors in 0..=code.correctable() {
+                let mut received = codeword.clone();
+                for position in index::sample(&mut rand, n, errors) {
+                    received[position] ^= rand.gen_range(1..=255);
+                }
+
+                assert_eq!(codeword, code.correct(&received));
+                assert_eq!(message, code.decode(&received));
+            }
+        }
+    }
+
+    #[test]
+    fn test_too_many_errors() {
+        let code = ReedSolomon::new(20, 12);
+        let message: Vec<u8> = (0..12).collect();
+        let codeword = code.encode(&message);
+
+        // 5 errors are past the 4 the code corrects, and detected here.
+        let mut received = codeword.clone();
+        for byte in &mut received[..5] {
+            *byte ^= 0xff;
+        }
+        assert_eq!(Err(Error::TooManyErrors), code.try_decode(&received));
+
+        assert_eq!(
+            Err(Error::LengthMismatch {
+                expected: 20,
+                found: 19
+            }),
+            code.try_decode(&codeword[1..])
+        );
+    }
+
+    #[test]
+    fn test_decoding_steps() {
+        let code = ReedSolomon::new(15, 9);
+        let mut received = code.encode(b"syndrome!");
+        received[2] ^= 0x42;
+        received[11] ^= 0x07;
+
+        // The errors are at the coefficients of degree 15 - 1 - 2 and 15 - 1 - 11.
+        let syndromes = code.syndromes(&received);
+        let locator = berlekamp_massey(&syndromes);
+        assert_eq!(2, locator.degree);
+
+        let positions = chien_search(&locator, 15);
+        assert_eq!(vec![3, 12], positions);
+        assert_eq!(
+            vec![Gf256::new(0x07), Gf256::new(0x42)],
+            forney(&syndromes, &locator, &positions)
+        );
+    }
+}
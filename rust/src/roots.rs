@@ -0,0 +1,180 @@
+//! Companion matrices, and roots of polynomials as their eigenvalues.
+//!
+//! The companion matrix of a polynomial of degree `n` is the `n`x`n` matrix whose
+//! characteristic polynomial is the polynomial made monic, so that its eigenvalues are the
+//! roots of the polynomial. Computing them with the QR algorithm in `f64` stays accurate for
+//! clustered and multiple roots, where iterating on the `f32` coefficients struggles.
+
+use crate::{
+    complex::Complex, element::Element, error::Error, matrix::Matrix, polynomial::Polynomial,
+};
+
+impl Polynomial {
+    /// Returns the companion matrix of the polynomial, whose characteristic polynomial is the
+    /// polynomial divided by its leading coefficient, see
+    /// [`Matrix::characteristic_polynomial`].
+    ///
+    /// Panics if the polynomial is constant, see [`Polynomial::try_companion_matrix`].
+    pub fn companion_matrix(&self) -> Matrix<Complex> {
+        self.try_companion_matrix()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the companion matrix of the polynomial, or an error if it's constant once
+    /// trimmed.
+    ///
+    /// For `p = c_0 + c_1 x + ... + c_n x^n`, it's the `n`x`n` matrix with ones right below
+    /// the diagonal and `-c_i / c_n` at (`i`, `n - 1`), zero everywhere else.
+    pub fn try_companion_matrix(&self) -> Result<Matrix<Complex>, Error> {
+        let p = self.clone().trim();
+        if p.degree == 0 {
+            return Err(Error::EmptyInput);
+        }
+
+        let n = p.degree;
+        let leading = p.coefficients[n];
+        let mut companion = Matrix::new(vec![Complex::ZERO; n * n], n, n);
+
+        for i in 1..n {
+            companion[(i, i - 1)] = Complex::one();
+        }
+        for (i, c) in p.coefficients[..n].iter().enumerate() {
+            companion[(i, n - 1)] = (*c / leading).neg();
+        }
+
+        Ok(companion)
+    }
+
+    /// Returns the roots of the polynomial, repeated according to their multiplicity and in
+    /// no particular order, as the eigenvalues of its companion matrix.
+    ///
+    /// Panics if the polynomial is constant or if the QR algorithm doesn't converge, see
+    /// [`Polynomial::try_roots_via_eigenvalues`].
+    pub fn roots_via_eigenvalues(&self) -> Vec<Complex> {
+        self.try_roots_via_eigenvalues()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the roots of the polynomial as the eigenvalues of its companion matrix, or an
+    /// error if it's constant or if the QR algorithm doesn't converge.
+    pub fn try_roots_via_eigenvalues(&self) -> Result<Vec<Complex>, Error> {
+        self.try_companion_matrix()?.try_eigenvalues()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{approx::Tolerance, assert_approx_eq, random};
+
+    fn complex(re: f32, im: f32) -> Complex {
+        Complex::new(re, im)
+    }
+
+    /// Returns the monic polynomial whose roots are `roots`.
+    fn from_roots(roots: &[Complex]) -> Polynomial {
+        roots.iter().fold(Polynomial::one(), |p, root| {
+            Polynomial::mul(&p, &Polynomial::new(vec![root.neg(), Complex::one()]))
+        })
+    }
+
+    /// Sort the roots so that they can be compared with expected ones.
+    fn sorted(mut roots: Vec<Complex>) -> Vec<Complex> {
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        roots
+    }
+
+    #[test]
+    fn test_companion_matrix() {
+        // 2 - 6x + 2x^2 becomes 1 - 3x + x^2
+        let p = Polynomial::new(vec![
+            complex(2.0, 0.0),
+            complex(-6.0, 0.0),
+            complex(2.0, 0.0),
+        ]);
+        assert_eq!(
+            Matrix::new(
+                vec![
+                    complex(0.0, 0.0),
+                    complex(-1.0, 0.0),
+                    complex(1.0, 0.0),
+                    complex(3.0, 0.0)
+                ],
+                2,
+                2
+            ),
+            p.companion_matrix()
+        );
+
+        // The characteristic polynomial of the companion matrix is the monic polynomial, and
+        // the companion matrix of a characteristic polynomial has the same eigenvalues.
+        let mut rand = random::seeded(12);
+        let q = Polynomial::random(&mut rand).trim();
+        let leading = q.coefficients[q.degree];
+        let monic = Polynomial::new(q.coefficients.iter().map(|c| *c / leading).collect());
+        assert_approx_eq!(
+            monic,
+            q.companion_matrix().characteristic_polynomial(),
+            Tolerance::new().absolute(1e-3)
+        );
+
+        let m = Matrix::new(
+            vec![
+                complex(2.0, 0.0),
+                complex(1.0, 0.0),
+                complex(0.0, 0.0),
+                complex(3.0, 0.0),
+            ],
+            2,
+            2,
+        );
+        assert_approx_eq!(
+            sorted(m.eigenvalues()),
+            sorted(m.characteristic_polynomial().roots_via_eigenvalues()),
+            Tolerance::new().absolute(1e-4)
+        );
+
+        assert_eq!(
+            Err(Error::EmptyInput),
+            Polynomial::new(vec![complex(3.0, 0.0), Complex::ZERO]).try_companion_matrix()
+        );
+    }
+
+    #[test]
+    fn test_roots_via_eigenvalues() {
+        let roots = vec![complex(-1.0, 0.0), complex(0.0, 1.0), complex(2.0, -0.5)];
+        let p = Polynomial::mul(
+            &from_roots(&roots),
+            &Polynomial::new(vec![complex(0.0, 3.0)]),
+        );
+        assert_approx_eq!(
+            sorted(roots),
+            sorted(p.roots_via_eigenvalues()),
+            Tolerance::new().absolute(1e-4)
+        );
+
+        // A triple root next to a simple one: rounding the coefficients to f32 perturbs a
+        // root of multiplicity 3 by about the cubic root of the machine epsilon.
+        let one = complex(1.0, 0.0);
+        let clustered = from_roots(&[one, one, one, complex(1.1, 0.0)]);
+        let found = sorted(clustered.roots_via_eigenvalues());
+        assert_approx_eq!(
+            [one, one, one][..],
+            found[..3],
+            Tolerance::new().absolute(2e-2)
+        );
+        assert_approx_eq!(
+            [complex(1.1, 0.0)][..],
+            found[3..],
+            Tolerance::new().absolute(1e-3)
+        );
+        for root in &found {
+            assert!(clustered.eval(*root).norm() < 1e-5, "{root}");
+        }
+
+        assert_eq!(
+            Err(Error::EmptyInput),
+            Polynomial::zero().try_roots_via_eigenvalues()
+        );
+    }
+}
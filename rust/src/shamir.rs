@@ -0,0 +1,165 @@
+//! Shamir's secret sharing over the prime field `GF(P)`.
+//!
+//! A secret is split into shares that are the values, at distinct non-zero points, of a
+//! random polynomial of degree `threshold - 1` whose constant term is the secret. Any
+//! `threshold` shares determine the polynomial, and its value at zero with it, while fewer
+//! shares are consistent with every possible secret.
+//!
+//! The randomness of the polynomial is the whole security of the scheme: shares meant to
+//! protect actual secrets must be generated from a cryptographically secure generator.
+
+use rand::Rng;
+
+use crate::{error::Error, finite_field::Gfp, polynomial::Polynomial};
+
+/// A share of a secret, the value `y` of the sharing polynomial at `x`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Share<const P: u32> {
+    pub x: Gfp<P>,
+    pub y: Gfp<P>,
+}
+
+/// Split `secret` into `shares` shares, any `threshold` of which recover it.
+///
+/// Panics if the parameters are out of range, see [`try_split`].
+pub fn split<const P: u32, R: Rng + ?Sized>(
+    secret: Gfp<P>,
+    threshold: usize,
+    shares: usize,
+    rand: &mut R,
+) -> Vec<Share<P>> {
+    try_split(secret, threshold, shares, rand).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Split `secret` into `shares` shares, any `threshold` of which recover it, or returns an
+/// error unless `1 <= threshold <= shares < P`.
+///
+/// The shares are the values of the sharing polynomial at `1, 2, ..., shares`.
+pub fn try_split<const P: u32, R: Rng + ?Sized>(
+    secret: Gfp<P>,
+    threshold: usize,
+    shares: usize,
+    rand: &mut R,
+) -> Result<Vec<Share<P>>, Error> {
+    if threshold == 0 || threshold > shares || shares >= P as usize {
+        return Err(Error::InvalidThreshold { threshold, shares });
+    }
+
+    let coefficients = std::iter::once(secret)
+        .chain((1..threshold).map(|_| Gfp::random(rand)))
+        .collect();
+    let polynomial = Polynomial::new(coefficients);
+
+    Ok((1..=shares as i64)
+        .map(|x| {
+            let x = Gfp::new(x);
+            Share {
+                x,
+                y: polynomial.eval(x),
+            }
+        })
+        .collect())
+}
+
+/// Recover the secret from shares.
+///
+/// Panics if there are no shares or if two of them have the same `x`, see [`try_combine`].
+pub fn combine<const P: u32>(shares: &[Share<P>]) -> Gfp<P> {
+    try_combine(shares).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Recover the secret from shares, as the value at zero of their Lagrange interpolation, or
+/// returns an error if there are no shares or if two of them have the same `x`.
+///
+/// Fewer shares than the threshold give a value unrelated to the secret, which can't be
+/// detected: that's what keeps the secret from them.
+pub fn try_combine<const P: u32>(shares: &[Share<P>]) -> Result<Gfp<P>, Error> {
+    if shares.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+
+    let points: Vec<_> = shares.iter().map(|share| (share.x, share.y)).collect();
+
+    Ok(Polynomial::try_interpolate(&points)?.eval(Gfp::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random;
+
+    /// The largest prime below `2^31`.
+    const P: u32 = 2147483647;
+
+    #[test]
+    fn test_split_combine() {
+        let mut rand = random::seeded(48);
+        let secret = Gfp::<P>::new(123456789);
+        let shares = split(secret, 3, 5, &mut rand);
+
+        assert_eq!(5, shares.len());
+        assert_eq!(Gfp::new(5), shares[4].x);
+
+        // Any 3 shares, in any order, recover the secret, and so do more.
+        for i in 0..5 {
+            for j in 0..5 {
+                for k in 0..5 {
+                    if i != j && j != k && i != k {
+                        assert_eq!(secret, combine(&[shares[i], shares[j], shares[k]]));
+                    }
+                }
+            }
+        }
+        assert_eq!(secret, combine(&shares));
+
+        // Two shares are consistent with any secret, and almost never give the actual one.
+        assert_ne!(secret, combine(&shares[..2]));
+
+        // A threshold of one just copies the secret.
+        let shares = split(secret, 1, 3, &mut rand);
+        assert!(shares.iter().all(|share| share.y == secret));
+    }
+
+    #[test]
+    fn test_small_field() {
+        // In GF(7), the 6 non-zero points are all the possible shares.
+        let mut rand = random::seeded(49);
+        for value in 0..7 {
+            let secret = Gfp::<7>::new(value);
+            let shares = split(secret, 4, 6, &mut rand);
+            assert_eq!(secret, combine(&shares[2..]));
+        }
+
+        assert_eq!(
+            Err(Error::InvalidThreshold {
+                threshold: 2,
+                shares: 7
+            }),
+            try_split(Gfp::<7>::new(1), 2, 7, &mut rand)
+        );
+        assert_eq!(
+            Err(Error::InvalidThreshold {
+                threshold: 4,
+                shares: 3
+            }),
+            try_split(Gfp::<7>::new(1), 4, 3, &mut rand)
+        );
+        assert_eq!(
+            Err(Error::InvalidThreshold {
+                threshold: 0,
+                shares: 3
+            }),
+            try_split(Gfp::<7>::new(1), 0, 3, &mut rand)
+        );
+
+        let shares = split(Gfp::<7>::new(1), 2, 3, &mut rand);
+        assert_eq!(
+            Err(Error::DuplicatePoint {
+                first: 0,
+                second: 2
+            }),
+            try_combine(&[shares[0], shares[1], shares[0]])
+        );
+        assert_eq!(Err(Error::EmptyInput), try_combine::<7>(&[]));
+    }
+}
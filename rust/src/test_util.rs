@@ -0,0 +1,13 @@
+//! Helpers shared by the unit tests.
+
+use crate::{complex::Complex, polynomial::Polynomial};
+
+/// Returns the polynomial with the real coefficients `coefficients`, constant first.
+pub(crate) fn poly(coefficients: &[f32]) -> Polynomial {
+    Polynomial::new(
+        coefficients
+            .iter()
+            .map(|&re| Complex::new(re, 0.0))
+            .collect(),
+    )
+}
@@ -0,0 +1,314 @@
+//! Column vectors, and their products with matrices.
+
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    complex::Complex, element::Element, error::Error, matrix::Matrix, polynomial::Polynomial,
+};
+
+/// A column vector, holding polynomials by default.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Vector<E = Polynomial> {
+    pub content: Vec<E>,
+}
+
+impl<E> Vector<E> {
+    pub fn new(content: Vec<E>) -> Vector<E> {
+        Vector { content }
+    }
+
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    fn check_same_len<F>(a: &Vector<E>, b: &Vector<F>) -> Result<(), Error> {
+        if a.len() != b.len() {
+            return Err(Error::LengthMismatch {
+                expected: a.len(),
+                found: b.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<E> From<Vec<E>> for Vector<E> {
+    fn from(content: Vec<E>) -> Self {
+        Vector::new(content)
+    }
+}
+
+impl<E> Index<usize> for Vector<E> {
+    type Output = E;
+
+    fn index(&self, index: usize) -> &E {
+        &self.content[index]
+    }
+}
+
+impl<E> IndexMut<usize> for Vector<E> {
+    fn index_mut(&mut self, index: usize) -> &mut E {
+        &mut self.content[index]
+    }
+}
+
+/// Returns the sum of the products of the pairs, accumulated with `mul_add`.
+fn sum_of_products<'a, E, M>(pairs: impl Iterator<Item = (&'a E, &'a E)>, mul_add: M) -> E
+where
+    E: Element + 'a,
+    M: Fn(&mut E, &E, &E),
+{
+    let mut sum = E::zero();
+    for (x, y) in pairs {
+        mul_add(&mut sum, x, y);
+    }
+
+    sum.normalized()
+}
+
+impl<E: Element> Vector<E> {
+    /// Returns the vector of `len` zeros.
+    pub fn zeros(len: usize) -> Vector<E> {
+        Vector::new(vec![E::zero(); len])
+    }
+
+    /// Add two vectors of the same length.
+    ///
+    /// Panics if their lengths don't match, see [`Vector::try_add`].
+    pub fn add(a: &Vector<E>, b: &Vector<E>) -> Vector<E> {
+        Self::try_add(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two vectors, returning an error if their lengths don't match.
+    pub fn try_add(a: &Vector<E>, b: &Vector<E>) -> Result<Vector<E>, Error> {
+        Self::check_same_len(a, b)?;
+
+        Ok(Vector::new(
+            a.content
+                .iter()
+                .zip(&b.content)
+                .map(|(x, y)| E::add(x, y))
+                .collect(),
+        ))
+    }
+
+    /// Returns the dot product of two vectors of the same length, the sum of the products of
+    /// their elements. Elements aren't conjugated.
+    ///
+    /// Panics if their lengths don't match, see [`Vector::try_dot`].
+    pub fn dot(a: &Vector<E>, b: &Vector<E>) -> E {
+        Self::try_dot(a, b).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the dot product of two vectors, or an error if their lengths don't match.
+    pub fn try_dot(a: &Vector<E>, b: &Vector<E>) -> Result<E, Error> {
+        Self::check_same_len(a, b)?;
+
+        Ok(sum_of_products(
+            a.content.iter().zip(&b.content),
+            E::mul_add,
+        ))
+    }
+}
+
+impl Vector<Polynomial> {
+    /// Add two vectors while restricting the contained polynomials to the provided ring
+    /// degree.
+    ///
+    /// Panics if their lengths don't match or if `ring` is 0, see [`Vector::try_add_in_ring`].
+    pub fn add_in_ring(a: &Vector, b: &Vector, ring: usize) -> Vector {
+        Self::try_add_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Add two vectors while restricting the contained polynomials to the provided ring
+    /// degree, returning an error if their lengths don't match or if `ring` is 0.
+    pub fn try_add_in_ring(a: &Vector, b: &Vector, ring: usize) -> Result<Vector, Error> {
+        Self::check_same_len(a, b)?;
+
+        Ok(Vector::new(
+            a.content
+                .iter()
+                .zip(&b.content)
+                .map(|(x, y)| Polynomial::try_add_in_ring(x, y, ring))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    /// Returns the dot product of two vectors while restricting the contained polynomials to
+    /// the provided ring degree.
+    ///
+    /// Panics if their lengths don't match or if `ring` is 0, see [`Vector::try_dot_in_ring`].
+    pub fn dot_in_ring(a: &Vector, b: &Vector, ring: usize) -> Polynomial {
+        Self::try_dot_in_ring(a, b, ring).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the dot product of two vectors while restricting the contained polynomials to
+    /// the provided ring degree, or an error if their lengths don't match or if `ring` is 0.
+    pub fn try_dot_in_ring(a: &Vector, b: &Vector, ring: usize) -> Result<Polynomial, Error> {
+        Self::check_same_len(a, b)?;
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        Ok(sum_of_products(
+            a.content.iter().zip(&b.content),
+            |acc, x, y| acc.mul_accumulate_in_ring(x, y, ring),
+        ))
+    }
+
+    /// Returns the Euclidean norm of the vector, over the coefficients of all its
+    /// polynomials.
+    pub fn norm(&self) -> f32 {
+        self.content
+            .iter()
+            .map(|p| p.norm() * p.norm())
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    /// Returns the largest modulus among the coefficients of all the polynomials of the
+    /// vector.
+    pub fn infinity_norm(&self) -> f32 {
+        self.content
+            .iter()
+            .map(Polynomial::infinity_norm)
+            .fold(0.0, f32::max)
+    }
+}
+
+impl Vector<Complex> {
+    /// Returns the Euclidean norm of the vector.
+    pub fn norm(&self) -> f32 {
+        self.content
+            .iter()
+            .map(|c| c.re * c.re + c.im * c.im)
+            .sum::<f32>()
+            .sqrt()
+    }
+}
+
+impl<E: Element> Matrix<E> {
+    /// Multiply the matrix by the column vector `v`, whose length must match the width of
+    /// the matrix.
+    ///
+    /// Panics if it doesn't, see [`Matrix::try_mul_vector`].
+    pub fn mul_vector(&self, v: &Vector<E>) -> Vector<E> {
+        self.try_mul_vector(v).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply the matrix by the column vector `v`, returning an error if the length of `v`
+    /// doesn't match the width of the matrix.
+    pub fn try_mul_vector(&self, v: &Vector<E>) -> Result<Vector<E>, Error> {
+        self.check_vector_len(v)?;
+
+        Ok(Vector::new(
+            self.rows()
+                .map(|row| sum_of_products(row.iter().zip(&v.content), E::mul_add))
+                .collect(),
+        ))
+    }
+
+    fn check_vector_len(&self, v: &Vector<E>) -> Result<(), Error> {
+        if self.width != v.len() {
+            return Err(Error::DimensionMismatch {
+                left: (self.width, self.height),
+                right: (1, v.len()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Matrix<Polynomial> {
+    /// Multiply the matrix by the column vector `v` while restricting the contained
+    /// polynomials to the provided ring degree.
+    ///
+    /// Panics if the length of `v` doesn't match the width of the matrix or if `ring` is 0,
+    /// see [`Matrix::try_mul_vector_in_ring`].
+    pub fn mul_vector_in_ring(&self, v: &Vector, ring: usize) -> Vector {
+        self.try_mul_vector_in_ring(v, ring)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Multiply the matrix by the column vector `v` while restricting the contained
+    /// polynomials to the provided ring degree, returning an error if the length of `v`
+    /// doesn't match the width of the matrix or if `ring` is 0.
+    pub fn try_mul_vector_in_ring(&self, v: &Vector, ring: usize) -> Result<Vector, Error> {
+        self.check_vector_len(v)?;
+        if ring == 0 {
+            return Err(Error::InvalidRing(ring));
+        }
+
+        Ok(Vector::new(
+            self.rows()
+                .map(|row| {
+                    sum_of_products(row.iter().zip(&v.content), |acc, x, y| {
+                        acc.mul_accumulate_in_ring(x, y, ring)
+                    })
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random, test_util::poly};
+
+    #[test]
+    fn test_dot_and_norm() {
+        let a = Vector::new(vec![poly(&[1.0, 1.0]), poly(&[0.0, 2.0])]);
+        let b = Vector::new(vec![poly(&[1.0, -1.0]), poly(&[3.0])]);
+
+        // (1 + x)(1 - x) + 6x = 1 + 6x - x2
+        assert_eq!(poly(&[1.0, 6.0, -1.0]), Vector::dot(&a, &b));
+        // x2 = 1 modulo x2 - 1
+        assert_eq!(poly(&[0.0, 6.0]), Vector::dot_in_ring(&a, &b, 2));
+        assert_eq!(
+            Vector::new(vec![poly(&[2.0]), poly(&[3.0, 2.0])]),
+            Vector::add(&a, &b)
+        );
+        assert!(Vector::try_dot(&a, &Vector::zeros(3)).is_err());
+
+        assert_eq!(6f32.sqrt(), a.norm());
+        assert_eq!(2.0, a.infinity_norm());
+        assert_eq!(
+            5.0,
+            Vector::new(vec![Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)]).norm()
+        );
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        let mut rand = random::seeded(10);
+        let m = Matrix::random(3, 4, &mut rand);
+        let v = Vector::new(Matrix::random(1, 3, &mut rand).content);
+
+        // The product matches the one with the vector as a width-1 matrix.
+        let as_matrix = Matrix::new(v.content.clone(), 1, 3);
+        assert_eq!(
+            Matrix::mul(&m, &as_matrix).content,
+            m.mul_vector(&v).content
+        );
+        assert_eq!(
+            Matrix::mul_in_ring(&m, &as_matrix, 2).content,
+            m.mul_vector_in_ring(&v, 2).content
+        );
+
+        assert_eq!(
+            Err(Error::DimensionMismatch {
+                left: (3, 4),
+                right: (1, 2)
+            }),
+            m.try_mul_vector(&Vector::zeros(2))
+        );
+        assert_eq!(Err(Error::InvalidRing(0)), m.try_mul_vector_in_ring(&v, 0));
+    }
+}
@@ -0,0 +1,241 @@
+//! Borrowed, possibly strided, views on the elements of a [`Matrix`].
+
+use std::ops::{Index, Range};
+
+use crate::{error::Error, matrix::Matrix};
+
+/// A rectangular selection of the elements of a [`Matrix`], borrowed from it.
+///
+/// Views select contiguous ranges of rows and columns with [`Matrix::view`], and can then
+/// skip rows and columns with [`MatrixView::step_by`]. They're indexed like matrices, by
+/// `(row, column)` relative to the view.
+#[derive(Debug)]
+pub struct MatrixView<'a, E> {
+    content: &'a [E],
+    offset: usize,
+    width: usize,
+    height: usize,
+    /// Distance in `content` between two consecutive rows of the view.
+    row_stride: usize,
+    /// Distance in `content` between two consecutive columns of the view.
+    column_stride: usize,
+}
+
+// Deriving them would require `E: Clone`, but views only hold a reference.
+impl<E> Clone for MatrixView<'_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for MatrixView<'_, E> {}
+
+impl<E> Matrix<E> {
+    /// Returns a view on the whole matrix.
+    pub fn as_view(&self) -> MatrixView<'_, E> {
+        MatrixView {
+            content: &self.content,
+            offset: 0,
+            width: self.width,
+            height: self.height,
+            row_stride: self.width,
+            column_stride: 1,
+        }
+    }
+
+    /// Returns a view on the elements in the `rows` and `columns` ranges.
+    ///
+    /// Panics if the ranges are empty or out of bounds, see [`Matrix::try_view`].
+    pub fn view(&self, rows: Range<usize>, columns: Range<usize>) -> MatrixView<'_, E> {
+        self.as_view().view(rows, columns)
+    }
+
+    /// Returns a view on the elements in the `rows` and `columns` ranges, or an error if the
+    /// ranges are empty or out of bounds.
+    pub fn try_view(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<MatrixView<'_, E>, Error> {
+        self.as_view().try_view(rows, columns)
+    }
+}
+
+impl<'a, E> MatrixView<'a, E> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the element at (`row`, `column`) of the view, or `None` if it's out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a E> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+
+        self.content
+            .get(self.offset + row * self.row_stride + column * self.column_stride)
+    }
+
+    /// Returns the element at (`row`, `column`), which must be in bounds.
+    fn at(&self, row: usize, column: usize) -> &'a E {
+        &self.content[self.offset + row * self.row_stride + column * self.column_stride]
+    }
+
+    /// Returns a view on the elements of this view in the `rows` and `columns` ranges.
+    ///
+    /// Panics if the ranges are empty or out of bounds, see [`MatrixView::try_view`].
+    pub fn view(&self, rows: Range<usize>, columns: Range<usize>) -> MatrixView<'a, E> {
+        self.try_view(rows, columns)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns a view on the elements of this view in the `rows` and `columns` ranges, or an
+    /// error if the ranges are empty or out of bounds.
+    pub fn try_view(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<MatrixView<'a, E>, Error> {
+        if rows.is_empty() || columns.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+
+        if rows.end > self.height || columns.end > self.width {
+            return Err(Error::OutOfBounds {
+                index: (rows.end - 1, columns.end - 1),
+                size: (self.width, self.height),
+            });
+        }
+
+        Ok(MatrixView {
+            offset: self.offset + rows.start * self.row_stride + columns.start * self.column_stride,
+            width: columns.len(),
+            height: rows.len(),
+            ..*self
+        })
+    }
+
+    /// Returns a view keeping every `row_step`-th row and every `column_step`-th column of
+    /// this view, starting with the first ones.
+    ///
+    /// Panics if a step is 0, like [`Iterator::step_by`].
+    pub fn step_by(&self, row_step: usize, column_step: usize) -> MatrixView<'a, E> {
+        assert!(
+            row_step != 0 && column_step != 0,
+            "view steps must be non-zero"
+        );
+
+        MatrixView {
+            width: self.width.div_ceil(column_step),
+            height: self.height.div_ceil(row_step),
+            row_stride: self.row_stride * row_step,
+            column_stride: self.column_stride * column_step,
+            ..*self
+        }
+    }
+
+    /// Returns an iterator over the rows of the view, each one being an iterator over its
+    /// elements from left to right.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = impl Iterator<Item = &'a E>> {
+        let view = *self;
+
+        (0..self.height).map(move |i| (0..view.width).map(move |j| view.at(i, j)))
+    }
+
+    /// Returns an iterator over the columns of the view, each one being an iterator over its
+    /// elements from top to bottom.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl Iterator<Item = &'a E>> {
+        let view = *self;
+
+        (0..self.width).map(move |j| (0..view.height).map(move |i| view.at(i, j)))
+    }
+}
+
+impl<E: Clone> MatrixView<'_, E> {
+    /// Copy the elements of the view into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<E> {
+        Matrix::new(
+            self.rows().flatten().cloned().collect(),
+            self.width,
+            self.height,
+        )
+    }
+}
+
+impl<E> Index<(usize, usize)> for MatrixView<'_, E> {
+    type Output = E;
+
+    /// Returns the element at (`row`, `column`) of the view.
+    ///
+    /// Panics if it's out of bounds, see [`MatrixView::get`].
+    fn index(&self, (row, column): (usize, usize)) -> &E {
+        self.get(row, column).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                Error::OutOfBounds {
+                    index: (row, column),
+                    size: (self.width, self.height),
+                }
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> Matrix<usize> {
+        Matrix::new((0..width * height).collect(), width, height)
+    }
+
+    #[test]
+    fn test_view() {
+        let m = numbered(5, 4);
+        let v = m.view(1..3, 2..5);
+
+        assert_eq!((3, 2), (v.width(), v.height()));
+        assert_eq!(7, v[(0, 0)]);
+        assert_eq!(Some(&14), v.get(1, 2));
+        assert_eq!(None, v.get(2, 0));
+        assert_eq!(Matrix::new(vec![7, 8, 9, 12, 13, 14], 3, 2), v.to_matrix());
+
+        // Views of views are relative to the inner view.
+        assert_eq!(13, v.view(1..2, 1..3)[(0, 0)]);
+
+        assert_eq!(
+            Err(Error::OutOfBounds {
+                index: (4, 1),
+                size: (5, 4)
+            }),
+            m.try_view(0..5, 0..2).map(|v| v.to_matrix())
+        );
+        assert!(m.try_view(2..2, 0..2).is_err());
+    }
+
+    #[test]
+    fn test_strided_view() {
+        let m = numbered(5, 5);
+        let v = m.as_view().step_by(2, 2);
+
+        assert_eq!(
+            Matrix::new(vec![0, 2, 4, 10, 12, 14, 20, 22, 24], 3, 3),
+            v.to_matrix()
+        );
+        assert_eq!(
+            vec![vec![2, 12, 22]],
+            v.view(0..3, 1..2)
+                .columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Matrix::new(vec![6, 9, 21, 24], 2, 2),
+            m.view(1..5, 1..5).step_by(3, 3).to_matrix()
+        );
+    }
+}